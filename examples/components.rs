@@ -0,0 +1,76 @@
+//! Components example — the ready-made themed views from `fleem::components`.
+
+use floem::event::EventListener;
+use floem::prelude::*;
+use floem::text::Weight;
+
+use fleem::components::{badge, button, card, checkbox, modal, progress_bar, text_input, toggle};
+use fleem::{Appearance, Fill, Size, ThemedExt, Variant};
+
+fn main() {
+    let _appearance = fleem::init(Appearance::Dark);
+
+    let name = RwSignal::new(String::new());
+    let agree = RwSignal::new(false);
+    let notify = RwSignal::new(true);
+    let progress = RwSignal::new(40.0_f64);
+    let show_modal = RwSignal::new(false);
+
+    let view = v_stack((
+        label(|| "Components".to_string())
+            .themed(|p, s| s.font_size(p.typography.font_title).font_weight(Weight::BOLD)),
+        card(
+            v_stack((
+                h_stack((
+                    label(|| "Account".to_string()).themed(|p, s| {
+                        s.font_size(p.typography.font_heading).font_weight(Weight::BOLD)
+                    }),
+                    empty().style(|s| s.flex_grow(1.0)),
+                    badge(|| "Beta", Variant::Info, Size::Small, Fill::Soft),
+                ))
+                .style(|s| s.items_center().width_full()),
+                text_input(name, Variant::Primary, Size::Normal),
+                checkbox(
+                    agree,
+                    || "I agree to the terms",
+                    Variant::Primary,
+                    Size::Normal,
+                ),
+                toggle(notify, || "Notifications", Variant::Success, Size::Normal),
+                progress_bar(move || progress.get()),
+                h_stack((
+                    button("+10%", Variant::Secondary, Size::Small, Fill::Filled)
+                        .action(move || progress.update(|v| *v = (*v + 10.0).min(100.0))),
+                    button("Open modal", Variant::Primary, Size::Small, Fill::Filled)
+                        .action(move || show_modal.set(true)),
+                ))
+                .style(|s| s.gap(8.0)),
+            ))
+            .style(|s| s.gap(12.0)),
+        ),
+        modal(
+            show_modal,
+            v_stack((
+                label(|| "Built with fleem::components::modal".to_string()),
+                button("Close", Variant::Primary, Size::Normal, Fill::Filled)
+                    .action(move || show_modal.set(false)),
+            ))
+            .style(|s| s.gap(12.0)),
+        ),
+    ))
+    .themed(|p, s| {
+        s.padding(24.0)
+            .gap(16.0)
+            .background(p.colors.bg_base)
+            .color(p.colors.text_primary)
+            .width_full()
+            .height_full()
+            .position(floem::style::Position::Relative)
+    });
+
+    floem::launch(move || {
+        view.on_event_stop(EventListener::WindowClosed, |_| {
+            floem::quit_app();
+        })
+    });
+}
@@ -4,23 +4,24 @@ use floem::event::EventListener;
 use floem::prelude::*;
 
 use fleem::styles::button_style;
-use fleem::{Appearance, Fill, Size, Variant};
+use fleem::{palette, Appearance, Fill, Size, Variant};
 
 fn button_row(
     variant_name: &'static str,
     variant: Variant,
     fill: Fill,
 ) -> impl IntoView {
-    let p = fleem::palette();
     h_stack((
+        button(format!("{variant_name} XLarge"))
+            .style(move |s| s.apply(button_style(&palette(), variant, Size::XLarge, fill))),
         button(format!("{variant_name} Large"))
-            .style(move |s| s.apply(button_style(&p, variant, Size::Large, fill))),
+            .style(move |s| s.apply(button_style(&palette(), variant, Size::Large, fill))),
         button(format!("{variant_name} Normal"))
-            .style(move |s| s.apply(button_style(&p, variant, Size::Normal, fill))),
+            .style(move |s| s.apply(button_style(&palette(), variant, Size::Normal, fill))),
         button(format!("{variant_name} Small"))
-            .style(move |s| s.apply(button_style(&p, variant, Size::Small, fill))),
+            .style(move |s| s.apply(button_style(&palette(), variant, Size::Small, fill))),
         button(format!("{variant_name} Tiny"))
-            .style(move |s| s.apply(button_style(&p, variant, Size::Tiny, fill))),
+            .style(move |s| s.apply(button_style(&palette(), variant, Size::Tiny, fill))),
     ))
     .style(|s| s.gap(8.0).items_center())
 }
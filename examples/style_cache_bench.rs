@@ -0,0 +1,46 @@
+//! Style cache benchmark — compares rebuilding recipes against `cached_style`.
+//!
+//! Run with `cargo run --release --example style_cache_bench`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use fleem::styles::button_style;
+use fleem::{cached_button_style, Appearance, Fill, Size, Variant};
+
+const ITERATIONS: u32 = 100_000;
+
+fn bench(name: &str, mut f: impl FnMut()) -> Duration {
+    // Warm up so the cached run doesn't pay for the first build.
+    for _ in 0..1_000 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<10} {:>10.1?} total, {:>8.1?} per style",
+        elapsed,
+        elapsed / ITERATIONS
+    );
+    elapsed
+}
+
+fn main() {
+    let _appearance = fleem::init(Appearance::Dark);
+
+    let rebuilt = bench("rebuilt", || {
+        let p = fleem::palette();
+        black_box(button_style(&p, Variant::Primary, Size::Normal, Fill::Filled));
+    });
+    let cached = bench("cached", || {
+        black_box(cached_button_style(Variant::Primary, Size::Normal, Fill::Filled));
+    });
+
+    println!(
+        "speedup    {:.1}x",
+        rebuilt.as_secs_f64() / cached.as_secs_f64()
+    );
+}
@@ -4,8 +4,7 @@ use floem::event::EventListener;
 use floem::prelude::*;
 use floem::text::Weight;
 
-use fleem::styles::{button_style, card_style, input_style};
-use fleem::{Appearance, Fill, Size, Variant};
+use fleem::{Appearance, Fill, Size, ThemedExt, Variant};
 
 fn main() {
     let appearance_signal = fleem::init(Appearance::Dark);
@@ -13,30 +12,18 @@ fn main() {
     let view = v_stack((
         // Title
         label(|| "Theme Switcher".to_string())
-            .style(move |s| {
-                let p = fleem::palette();
-                s.font_size(p.typography.font_title).font_weight(Weight::BOLD)
-            }),
+            .themed(|p, s| s.font_size(p.typography.font_title).font_weight(Weight::BOLD)),
         // Theme buttons
         h_stack((
             button("Dark")
                 .action(move || appearance_signal.set(Appearance::Dark))
-                .style(move |s| {
-                    let p = fleem::palette();
-                    s.apply(button_style(&p, Variant::Primary, Size::Normal, Fill::Filled))
-                }),
+                .themed_button(Variant::Primary, Size::Normal, Fill::Filled),
             button("Light")
                 .action(move || appearance_signal.set(Appearance::Light))
-                .style(move |s| {
-                    let p = fleem::palette();
-                    s.apply(button_style(&p, Variant::Secondary, Size::Normal, Fill::Filled))
-                }),
+                .themed_button(Variant::Secondary, Size::Normal, Fill::Filled),
             button("System")
                 .action(move || appearance_signal.set(Appearance::System))
-                .style(move |s| {
-                    let p = fleem::palette();
-                    s.apply(button_style(&p, Variant::Neutral, Size::Normal, Fill::Outlined))
-                }),
+                .themed_button(Variant::Neutral, Size::Normal, Fill::Outlined),
         ))
         .style(|s| s.gap(8.0)),
         // Current appearance label
@@ -62,35 +49,20 @@ fn main() {
                         s.font_size(p.typography.font_body).color(p.colors.text_secondary)
                     }),
                 text_input(RwSignal::new("Type something here...".to_string()))
-                    .style(move |s| {
-                        let p = fleem::palette();
-                        s.apply(input_style(&p))
-                    }),
+                    .themed_input(),
                 h_stack((
                     button("Primary")
-                        .style(move |s| {
-                            let p = fleem::palette();
-                            s.apply(button_style(&p, Variant::Primary, Size::Normal, Fill::Filled))
-                        }),
+                        .themed_button(Variant::Primary, Size::Normal, Fill::Filled),
                     button("Success")
-                        .style(move |s| {
-                            let p = fleem::palette();
-                            s.apply(button_style(&p, Variant::Success, Size::Normal, Fill::Filled))
-                        }),
+                        .themed_button(Variant::Success, Size::Normal, Fill::Filled),
                     button("Error")
-                        .style(move |s| {
-                            let p = fleem::palette();
-                            s.apply(button_style(&p, Variant::Error, Size::Normal, Fill::Outlined))
-                        }),
+                        .themed_button(Variant::Error, Size::Normal, Fill::Outlined),
                 ))
                 .style(|s| s.gap(8.0)),
             ))
             .style(|s| s.gap(12.0)),
         )
-        .style(move |s| {
-            let p = fleem::palette();
-            s.apply(card_style(&p))
-        }),
+        .themed_card(),
     ))
     .style(move |s| {
         let p = fleem::palette();
@@ -0,0 +1,126 @@
+//! Color and scalar interpolation in the OKLab perceptual space
+
+use floem::prelude::Color;
+
+/// Interpolate between two scalars. `t` is clamped to `0.0..=1.0`.
+pub(crate) fn lerp_f32(a: f32, b: f32, t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+    a + (b - a) * t
+}
+
+/// Interpolate between two colors in OKLab with premultiplied alpha.
+///
+/// OKLab keeps perceived lightness even along the blend, so midpoints don't
+/// turn muddy the way sRGB averages do. Premultiplying keeps fades to or
+/// from `Color::TRANSPARENT` from passing through the transparent color's
+/// (black) channels. `t` is clamped to `0.0..=1.0`.
+pub fn lerp_color(a: Color, b: Color, t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    if t == 0.0 {
+        return a;
+    }
+    if t == 1.0 {
+        return b;
+    }
+
+    let t = t as f64;
+    let alpha_a = a.a as f64 / 255.0;
+    let alpha_b = b.a as f64 / 255.0;
+    let alpha = alpha_a + (alpha_b - alpha_a) * t;
+    if alpha <= 0.0 {
+        return Color::TRANSPARENT;
+    }
+
+    let lab_a = to_oklab(a);
+    let lab_b = to_oklab(b);
+    let lab: [f64; 3] =
+        std::array::from_fn(|i| (lab_a[i] * alpha_a * (1.0 - t) + lab_b[i] * alpha_b * t) / alpha);
+
+    let [r, g, b] = from_oklab(lab);
+    Color::rgba8(r, g, b, (alpha * 255.0).round() as u8)
+}
+
+/// WCAG contrast ratio between two colors, ignoring alpha.
+pub(crate) fn contrast_ratio(a: Color, b: Color) -> f64 {
+    let (la, lb) = (luminance(a), luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// `fg` moved toward black or white, whichever contrasts more with `bg`,
+/// just far enough to reach a contrast ratio of `min` on `bg`.
+pub(crate) fn readable_on(fg: Color, bg: Color, min: f64) -> Color {
+    if contrast_ratio(fg, bg) >= min {
+        return fg;
+    }
+    let black = Color::rgba8(0, 0, 0, fg.a);
+    let white = Color::rgba8(255, 255, 255, fg.a);
+    let extreme = if contrast_ratio(black, bg) > contrast_ratio(white, bg) {
+        black
+    } else {
+        white
+    };
+    // Contrast grows monotonically toward the extreme, so bisect for the
+    // smallest shift that reaches `min`.
+    let (mut lo, mut hi) = (0.0_f32, 1.0_f32);
+    for _ in 0..16 {
+        let mid = (lo + hi) / 2.0;
+        if contrast_ratio(lerp_color(fg, extreme, mid), bg) >= min {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    lerp_color(fg, extreme, hi)
+}
+
+/// Relative luminance as defined by WCAG.
+fn luminance(c: Color) -> f64 {
+    0.2126 * srgb_to_linear(c.r) + 0.7152 * srgb_to_linear(c.g) + 0.0722 * srgb_to_linear(c.b)
+}
+
+fn to_oklab(c: Color) -> [f64; 3] {
+    let r = srgb_to_linear(c.r);
+    let g = srgb_to_linear(c.g);
+    let b = srgb_to_linear(c.b);
+
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ]
+}
+
+fn from_oklab([l, a, b]: [f64; 3]) -> [u8; 3] {
+    let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+    [
+        linear_to_srgb(4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_),
+        linear_to_srgb(-1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_),
+        linear_to_srgb(-0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_),
+    ]
+}
+
+fn srgb_to_linear(c: u8) -> f64 {
+    let c = c as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
@@ -0,0 +1,191 @@
+//! Memoized style recipes keyed by theme, appearance, and recipe parameters
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use floem::style::Style;
+
+use crate::density::{density_in, Density};
+use crate::motion::reduced_motion;
+use crate::scale::ui_scale;
+use crate::scope::current_scope;
+use crate::styles;
+use crate::theme::{palette, theme_key, ResolvedAppearance};
+use crate::transition;
+use crate::variant::{Fill, Size, Variant};
+
+/// Style recipe that can be memoized by [`cached_style`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Recipe {
+    Button,
+    IconButton,
+    Badge,
+    Checkbox,
+    Toggle,
+    Input,
+    Card,
+    DropZone,
+    ProgressTrack,
+    ModalCard,
+}
+
+impl Recipe {
+    /// Whether the recipe output depends on `Variant`, `Size`, and `Fill`.
+    fn uses_variant(self) -> bool {
+        matches!(
+            self,
+            Recipe::Button | Recipe::IconButton | Recipe::Badge | Recipe::Checkbox | Recipe::Toggle
+        )
+    }
+
+    fn uses_size_and_fill(self) -> bool {
+        matches!(self, Recipe::Button | Recipe::IconButton)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct CacheKey {
+    recipe: Recipe,
+    variant: Variant,
+    size: Size,
+    fill: Fill,
+}
+
+/// Theme id, resolved appearance, density, UI scale (as bits), and reduced
+/// motion: the palette state a cached style was built for.
+type Epoch = (u64, ResolvedAppearance, Density, u32, bool);
+
+/// Most epochs kept at once. Every scope or window resolving to a different
+/// palette uses its own; the least recently used one is dropped first.
+const MAX_EPOCHS: usize = 8;
+
+#[derive(Default)]
+struct StyleCache {
+    /// Cached styles per epoch, least recently used first.
+    buckets: Vec<(Epoch, HashMap<CacheKey, Style>)>,
+}
+
+impl StyleCache {
+    /// The styles built for `epoch`, marking it as most recently used.
+    fn bucket(&mut self, epoch: Epoch) -> &mut HashMap<CacheKey, Style> {
+        match self.buckets.iter().position(|(e, _)| *e == epoch) {
+            Some(i) if i + 1 == self.buckets.len() => {}
+            Some(i) => {
+                let bucket = self.buckets.remove(i);
+                self.buckets.push(bucket);
+            }
+            None => {
+                if self.buckets.len() == MAX_EPOCHS {
+                    self.buckets.remove(0);
+                }
+                self.buckets.push((epoch, HashMap::new()));
+            }
+        }
+        &mut self.buckets.last_mut().expect("bucket was just pushed").1
+    }
+}
+
+thread_local! {
+    static STYLE_CACHE: RefCell<StyleCache> = RefCell::new(StyleCache::default());
+}
+
+/// Get a prebuilt `Style` for a recipe, building it on first use.
+///
+/// The cache is keyed by the active theme, the resolved appearance, the
+/// density and UI scale, the recipe, and its `Variant`/`Size`/`Fill` parameters.
+/// Parameters a recipe ignores are normalized so they don't create
+/// duplicate entries.
+///
+/// This reads the reactive `Appearance` signal, so calling it inside a
+/// `.style(|s| ...)` closure re-runs the closure when the appearance changes.
+/// Styles are built from the palette of [`crate::current_scope`], which a
+/// [`ThemedExt::themed`](crate::ThemedExt::themed) closure keeps set when it
+/// re-runs, and kept separately for each theme, appearance, density, scale, and motion
+/// preference in use, so scopes that differ don't evict each other. Nothing
+/// is cached while a palette transition is running.
+pub fn cached_style(recipe: Recipe, variant: Variant, size: Size, fill: Fill) -> Style {
+    let (theme_id, resolved) = theme_key();
+    let epoch = (
+        theme_id,
+        resolved,
+        density_in(current_scope()),
+        ui_scale().factor().to_bits(),
+        reduced_motion(),
+    );
+    let key = CacheKey {
+        recipe,
+        variant: if recipe.uses_variant() {
+            variant
+        } else {
+            Variant::default()
+        },
+        size: if recipe.uses_size_and_fill() {
+            size
+        } else {
+            Size::default()
+        },
+        fill: if recipe.uses_size_and_fill() {
+            fill
+        } else {
+            Fill::default()
+        },
+    };
+
+    let cached = STYLE_CACHE.with(|cache| cache.borrow_mut().bucket(epoch).get(&key).cloned());
+    if let Some(style) = cached
+        && !transition::in_progress()
+    {
+        return style;
+    }
+
+    // Building may start a palette transition. Styles built mid-transition
+    // use an interpolated palette, so they are returned but not stored.
+    let style = build(key);
+    if !transition::in_progress() {
+        STYLE_CACHE.with(|cache| cache.borrow_mut().bucket(epoch).insert(key, style.clone()));
+    }
+    style
+}
+
+/// Cached equivalent of [`styles::button_style`] for the current palette.
+pub fn cached_button_style(variant: Variant, size: Size, fill: Fill) -> Style {
+    cached_style(Recipe::Button, variant, size, fill)
+}
+
+/// Cached equivalent of [`styles::badge_style`] for the current palette.
+pub fn cached_badge_style(variant: Variant) -> Style {
+    cached_style(Recipe::Badge, variant, Size::default(), Fill::default())
+}
+
+/// Drop every cached style.
+///
+/// Not needed for theme or appearance changes, which invalidate the cache
+/// automatically. Useful after mutating state the cache can't observe.
+pub fn clear_style_cache() {
+    STYLE_CACHE.with(|cache| cache.borrow_mut().buckets.clear());
+}
+
+#[cfg(test)]
+pub(crate) fn cached_len() -> usize {
+    STYLE_CACHE.with(|cache| {
+        let cache = cache.borrow();
+        cache.buckets.iter().map(|(_, styles)| styles.len()).sum()
+    })
+}
+
+/// Build the style for `key` from the palette of the enclosing scope.
+fn build(key: CacheKey) -> Style {
+    let p = palette();
+    match key.recipe {
+        Recipe::Button => styles::button_style(&p, key.variant, key.size, key.fill),
+        Recipe::IconButton => styles::icon_button_style(&p, key.variant, key.size, key.fill),
+        Recipe::Badge => styles::badge_style(&p, key.variant),
+        Recipe::Checkbox => styles::checkbox_style(&p, key.variant),
+        Recipe::Toggle => styles::toggle_style(&p, key.variant),
+        Recipe::Input => styles::input_style(&p),
+        Recipe::Card => styles::card_style(&p),
+        Recipe::DropZone => styles::drop_zone_style(&p),
+        Recipe::ProgressTrack => styles::progress_track_style(&p),
+        Recipe::ModalCard => styles::modal_card_style(&p),
+    }
+}
@@ -0,0 +1,24 @@
+//! Themed badge
+
+use std::fmt::Display;
+
+use floem::views::{label, Label};
+
+use crate::ext::ThemedExt;
+use crate::styles::badge_fill_style;
+use crate::variant::{Fill, Size, Variant};
+
+/// A label styled with [`crate::styles::badge_fill_style`].
+///
+/// `size` sets the text size, as [`Size::font_size`] does for buttons.
+pub fn badge<S: Display + 'static>(
+    text: impl Fn() -> S + 'static,
+    variant: Variant,
+    size: Size,
+    fill: Fill,
+) -> Label {
+    label(text).themed(move |p, s| {
+        s.apply(badge_fill_style(p, variant, fill))
+            .font_size(size.font_size(p))
+    })
+}
@@ -0,0 +1,26 @@
+//! Themed button
+
+use floem::views::Button;
+use floem::IntoView;
+
+use crate::ext::ThemedExt;
+use crate::variant::{Fill, Size, Variant};
+
+/// A button styled with [`crate::styles::button_style`].
+///
+/// Chain `.action(...)` to handle clicks.
+pub fn button(child: impl IntoView + 'static, variant: Variant, size: Size, fill: Fill) -> Button {
+    floem::views::button(child).themed_button(variant, size, fill)
+}
+
+/// An icon-only button styled with [`crate::styles::icon_button_style`].
+///
+/// `icon` is usually an `svg` view or a single-glyph label.
+pub fn icon_button(
+    icon: impl IntoView + 'static,
+    variant: Variant,
+    size: Size,
+    fill: Fill,
+) -> Button {
+    floem::views::button(icon).themed_icon_button(variant, size, fill)
+}
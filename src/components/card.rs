@@ -0,0 +1,11 @@
+//! Themed card
+
+use floem::views::{container, Container};
+use floem::IntoView;
+
+use crate::ext::ThemedExt;
+
+/// A container styled with [`crate::styles::card_style`].
+pub fn card(child: impl IntoView + 'static) -> Container {
+    container(child).themed_card()
+}
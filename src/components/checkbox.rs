@@ -0,0 +1,36 @@
+//! Themed labeled checkbox
+
+use std::fmt::Display;
+
+use floem::style::CursorStyle;
+use floem::views::{h_stack, label, Decorators, Stack};
+use floem_reactive::{RwSignal, SignalGet, SignalUpdate};
+
+use crate::ext::ThemedExt;
+use crate::variant::{Size, Variant};
+
+/// A checkbox with a trailing label, bound to `checked`.
+///
+/// Clicking the box or the label toggles the value once. The row is styled
+/// with [`crate::styles::checkbox_style`], and `size` sets the label's text
+/// size, as [`Size::font_size`] does for buttons.
+pub fn checkbox<S: Display + 'static>(
+    checked: RwSignal<bool>,
+    text: impl Fn() -> S + 'static,
+    variant: Variant,
+    size: Size,
+) -> Stack {
+    h_stack((
+        // The box toggles itself; its update is the only place the value
+        // changes for clicks and keyboard presses on it.
+        floem::views::checkbox(move || checked.get())
+            .on_update(move |v| checked.set(v))
+            .themed(move |p, s| {
+                s.cursor(CursorStyle::Pointer)
+                    .apply_if(checked.get(), |s| s.border_color(variant.accent(p)))
+            }),
+        label(text).on_click_stop(move |_| checked.update(|v| *v = !*v)),
+    ))
+    .themed_checkbox(variant)
+    .themed(move |p, s| s.font_size(size.font_size(p)))
+}
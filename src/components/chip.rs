@@ -0,0 +1,33 @@
+//! Themed chip
+
+use std::fmt::Display;
+
+use floem::views::{label, Label};
+
+use crate::ext::ThemedExt;
+use crate::styles::chip_style;
+use crate::variant::{Fill, Size, Variant};
+
+/// A label styled with [`crate::styles::chip_style`].
+///
+/// `size` sets the text size, as [`Size::font_size`] does for buttons; the
+/// recipe's own text size matches `Size::Small`. Pass `selected` to render it
+/// as an on/off filter chip; toggle the signal from `on_click_stop` to make it
+/// interactive.
+pub fn chip<S: Display + 'static>(
+    text: impl Fn() -> S + 'static,
+    variant: Variant,
+    size: Size,
+    fill: Fill,
+    selected: impl Fn() -> bool + 'static,
+) -> Label {
+    // Selected chips take the `Fill::Filled` colors, as the recipe's
+    // `selected` state does.
+    label(text).themed(move |p, s| {
+        s.apply(chip_style(p, variant, fill))
+            .apply_if(selected(), |s| {
+                s.apply(chip_style(p, variant, Fill::Filled))
+            })
+            .font_size(size.font_size(p))
+    })
+}
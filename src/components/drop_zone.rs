@@ -0,0 +1,14 @@
+//! Themed drop zone
+
+use floem::views::{container, Container};
+use floem::IntoView;
+
+use crate::ext::ThemedExt;
+use crate::styles::drop_zone_style;
+
+/// A container styled with [`crate::styles::drop_zone_style`].
+///
+/// Attach floem's drag-and-drop listeners to the returned view.
+pub fn drop_zone(child: impl IntoView + 'static) -> Container {
+    container(child).themed(|p, s| s.apply(drop_zone_style(p)))
+}
@@ -0,0 +1,25 @@
+//! Themed text input
+
+use floem::views::TextInput;
+use floem_reactive::RwSignal;
+
+use crate::ext::ThemedExt;
+use crate::styles::input_style;
+use crate::variant::{Size, Variant};
+
+/// A text input bound to `buffer`, styled with [`crate::styles::input_style`].
+///
+/// `size` sets the text size, as [`Size::font_size`] does for buttons.
+/// `Variant::Primary` keeps the recipe's neutral border; other variants color
+/// the border with [`Variant::accent`], e.g. `Variant::Error` for a field that
+/// failed validation.
+pub fn text_input(buffer: RwSignal<String>, variant: Variant, size: Size) -> TextInput {
+    floem::views::text_input(buffer).themed(move |p, s| {
+        let accent = variant.accent(p);
+        s.apply(input_style(p))
+            .font_size(size.font_size(p))
+            .apply_if(variant != Variant::Primary, |s| {
+                s.border_color(accent).focus(|s| s.border_color(accent))
+            })
+    })
+}
@@ -0,0 +1,27 @@
+//! Ready-made themed views
+//!
+//! Each function builds a floem view and applies the matching recipe from
+//! [`crate::styles`] through [`crate::ThemedExt`], so it restyles when the
+//! appearance changes. Returned views accept further `.style()` calls.
+
+mod badge;
+mod button;
+mod card;
+mod checkbox;
+mod chip;
+mod drop_zone;
+mod input;
+pub(crate) mod modal;
+mod progress;
+pub(crate) mod toggle;
+
+pub use badge::badge;
+pub use button::{button, icon_button};
+pub use card::card;
+pub use checkbox::checkbox;
+pub use chip::chip;
+pub use drop_zone::drop_zone;
+pub use input::text_input;
+pub use modal::{modal, Modal};
+pub use progress::progress_bar;
+pub use toggle::toggle;
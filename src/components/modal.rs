@@ -0,0 +1,277 @@
+//! Themed modal dialog with keyboard and focus handling
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+
+use floem::context::UpdateCx;
+use floem::event::{Event, EventListener, EventPropagation};
+use floem::keyboard::{Key, NamedKey};
+use floem::style::Style;
+use floem::views::{container, stack, Container, Decorators};
+use floem::{AppState, IntoView, View, ViewId};
+use floem_reactive::{create_effect, RwSignal, SignalGet, SignalUpdate};
+
+use crate::ext::ThemedExt;
+use crate::styles::{modal_backdrop_style_at, modal_card_style};
+
+thread_local! {
+    /// Open modals, bottom-most first, with the depth each was opened at.
+    static MODAL_STACK: RefCell<Vec<(u64, u32)>> = const { RefCell::new(Vec::new()) };
+    static NEXT_MODAL_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Push modal `id` and return its depth, one above the top-most open modal.
+pub(crate) fn push_modal(id: u64) -> u32 {
+    MODAL_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let depth = stack.last().map_or(0, |&(_, depth)| depth + 1);
+        stack.push((id, depth));
+        depth
+    })
+}
+
+/// Remove modal `id` from the stack, wherever it sits.
+pub(crate) fn remove_modal(id: u64) {
+    MODAL_STACK.with(|stack| stack.borrow_mut().retain(|&(open, _)| open != id));
+}
+
+/// Index of the Tab stop after `current` (before it when `backwards`),
+/// wrapping around. Focus outside the order moves to its first or last stop.
+pub(crate) fn next_focus(current: Option<usize>, len: usize, backwards: bool) -> usize {
+    match (current, backwards) {
+        (Some(i), false) => (i + 1) % len,
+        (Some(i), true) => (i + len - 1) % len,
+        (None, false) => 0,
+        (None, true) => len - 1,
+    }
+}
+
+/// Focus change requested of a [`FocusKeeper`].
+enum FocusMove {
+    Open,
+    Close,
+}
+
+/// Invisible view that moves focus into the modal when it opens and back to
+/// the previously focused view when it closes.
+///
+/// floem only exposes the focused view through `AppState`, so the view that
+/// had focus before the modal opened is looked up in `update`.
+struct FocusKeeper {
+    id: ViewId,
+    /// View focused when the modal opens.
+    target: ViewId,
+    /// View focused when the modal closes, instead of the opener.
+    restore: Option<ViewId>,
+    /// View that had focus when the modal opened.
+    opener: Option<ViewId>,
+}
+
+impl View for FocusKeeper {
+    fn id(&self) -> ViewId {
+        self.id
+    }
+
+    fn view_style(&self) -> Option<Style> {
+        Some(Style::new().absolute().width(0.0).height(0.0))
+    }
+
+    fn update(&mut self, cx: &mut UpdateCx, state: Box<dyn Any>) {
+        let Ok(step) = state.downcast::<FocusMove>() else {
+            return;
+        };
+        match *step {
+            FocusMove::Open => {
+                self.opener = focused_view(self.id, cx.app_state());
+                self.target.request_focus();
+            }
+            FocusMove::Close => {
+                if let Some(id) = self.restore.or(self.opener.take()) {
+                    id.request_focus();
+                }
+            }
+        }
+    }
+}
+
+/// The focused view in the window containing `from`, if any.
+fn focused_view(from: ViewId, state: &AppState) -> Option<ViewId> {
+    let mut root = from;
+    while let Some(parent) = root.parent() {
+        root = parent;
+    }
+    let mut pending = vec![root];
+    while let Some(id) = pending.pop() {
+        if state.is_focused(&id) {
+            return Some(id);
+        }
+        pending.extend(id.children());
+    }
+    None
+}
+
+/// Builder for a modal dialog shown while its `open` signal is true.
+///
+/// The dialog:
+/// - closes on Escape and on clicks outside the card (both configurable),
+/// - moves focus into the card when opened, and back to the view that had
+///   focus before (usually its trigger) when closed,
+/// - traps Tab / Shift+Tab inside the card, cycling through `focus_order`
+///   from whichever of those views has focus, including after a click,
+/// - stacks above every modal that is already open.
+///
+/// The backdrop is absolutely positioned, so place the modal as the last
+/// child of a relatively positioned container that it should cover.
+pub struct Modal {
+    open: RwSignal<bool>,
+    close_on_escape: bool,
+    close_on_backdrop: bool,
+    focus_order: Vec<ViewId>,
+    restore_focus: Option<ViewId>,
+}
+
+impl Modal {
+    /// Create a modal controlled by `open`.
+    pub fn new(open: RwSignal<bool>) -> Self {
+        Self {
+            open,
+            close_on_escape: true,
+            close_on_backdrop: true,
+            focus_order: Vec::new(),
+            restore_focus: None,
+        }
+    }
+
+    /// Close the modal when Escape is pressed. Defaults to `true`.
+    pub fn close_on_escape(mut self, close: bool) -> Self {
+        self.close_on_escape = close;
+        self
+    }
+
+    /// Close the modal when the backdrop is clicked. Defaults to `true`.
+    pub fn close_on_backdrop(mut self, close: bool) -> Self {
+        self.close_on_backdrop = close;
+        self
+    }
+
+    /// Views inside the dialog that Tab cycles through, in order.
+    ///
+    /// The first one receives focus when the modal opens. Without a focus
+    /// order the card itself is focused and Tab keeps focus there.
+    pub fn focus_order(mut self, ids: impl IntoIterator<Item = ViewId>) -> Self {
+        self.focus_order = ids.into_iter().collect();
+        self
+    }
+
+    /// View to focus after the modal closes, instead of the one that had
+    /// focus when it opened.
+    pub fn restore_focus(mut self, id: ViewId) -> Self {
+        self.restore_focus = Some(id);
+        self
+    }
+
+    /// Build the modal around `content`.
+    pub fn view(self, content: impl IntoView + 'static) -> Container {
+        let Modal {
+            open,
+            close_on_escape,
+            close_on_backdrop,
+            focus_order,
+            restore_focus,
+        } = self;
+        let modal_id = NEXT_MODAL_ID.with(|n| n.replace(n.get() + 1));
+        let depth = RwSignal::new(0_u32);
+        // Index in `focus_order` of the focused view, kept in sync by focus
+        // events so Tab continues from wherever a click put focus.
+        let focused = RwSignal::new(None::<usize>);
+        for (i, id) in focus_order.iter().enumerate() {
+            id.add_event_listener(
+                EventListener::FocusGained,
+                Box::new(move |_| {
+                    focused.set(Some(i));
+                    EventPropagation::Continue
+                }),
+            );
+            id.add_event_listener(
+                EventListener::FocusLost,
+                Box::new(move |_| {
+                    if focused.get_untracked() == Some(i) {
+                        focused.set(None);
+                    }
+                    EventPropagation::Continue
+                }),
+            );
+        }
+
+        let card = container(content)
+            .keyboard_navigable()
+            .themed(|p, s| s.apply(modal_card_style(p)))
+            // Keep clicks inside the card from reaching the backdrop.
+            .on_click_stop(|_| {});
+        let card_id = card.id();
+
+        let order = focus_order.clone();
+        let card = card.on_event(EventListener::KeyDown, move |event| {
+            let Event::KeyDown(key_event) = event else {
+                return EventPropagation::Continue;
+            };
+            match key_event.key.logical_key {
+                Key::Named(NamedKey::Escape) if close_on_escape => {
+                    open.set(false);
+                    EventPropagation::Stop
+                }
+                Key::Named(NamedKey::Tab) => {
+                    if !order.is_empty() {
+                        let backwards = key_event.modifiers.shift();
+                        let next = next_focus(focused.get_untracked(), order.len(), backwards);
+                        focused.set(Some(next));
+                        order[next].request_focus();
+                    }
+                    EventPropagation::Stop
+                }
+                _ => EventPropagation::Continue,
+            }
+        });
+
+        let keeper = FocusKeeper {
+            id: ViewId::new(),
+            target: focus_order.first().copied().unwrap_or(card_id),
+            restore: restore_focus,
+            opener: None,
+        };
+        let keeper_id = keeper.id;
+
+        create_effect(move |was_open: Option<bool>| {
+            let is_open = open.get();
+            match (was_open.unwrap_or(false), is_open) {
+                (false, true) => {
+                    depth.set(push_modal(modal_id));
+                    keeper_id.update_state(FocusMove::Open);
+                }
+                (true, false) => {
+                    remove_modal(modal_id);
+                    keeper_id.update_state(FocusMove::Close);
+                }
+                _ => {}
+            }
+            is_open
+        });
+
+        container(stack((card, keeper)))
+            .themed(move |p, s| s.apply(modal_backdrop_style_at(p, open.get(), depth.get())))
+            .on_click_stop(move |_| {
+                if close_on_backdrop {
+                    open.set(false);
+                }
+            })
+            // A modal disposed while open must not keep its stack slot.
+            .on_cleanup(move || remove_modal(modal_id))
+    }
+}
+
+/// A modal dialog shown while `open` is true, with default behavior.
+///
+/// Shorthand for `Modal::new(open).view(content)`.
+pub fn modal(open: RwSignal<bool>, content: impl IntoView + 'static) -> Container {
+    Modal::new(open).view(content)
+}
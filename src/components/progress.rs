@@ -0,0 +1,15 @@
+//! Themed progress bar
+
+use floem::views::{container, empty, Container};
+
+use crate::ext::ThemedExt;
+use crate::styles::{progress_fill_style, progress_track_style};
+
+/// A progress bar whose fill tracks `pct` (0.0–100.0).
+///
+/// `pct` is read inside a style closure, so passing a signal getter keeps
+/// the fill in sync.
+pub fn progress_bar(pct: impl Fn() -> f64 + 'static) -> Container {
+    container(empty().themed(move |p, s| s.apply(progress_fill_style(p, pct()))))
+        .themed(|p, s| s.apply(progress_track_style(p)))
+}
@@ -0,0 +1,36 @@
+//! Themed labeled toggle switch
+
+use std::fmt::Display;
+
+use floem::views::{h_stack, label, toggle_button, Stack};
+use floem_reactive::{RwSignal, SignalGet, SignalUpdate};
+
+use crate::ext::ThemedExt;
+use crate::styles::toggle_track_style;
+use crate::variant::{Size, Variant};
+
+/// A toggle switch with a trailing label, bound to `on`.
+///
+/// The track is styled with [`crate::styles::toggle_track_style`] and the
+/// row with [`crate::styles::toggle_style`]. `size` sets the label's text
+/// size, as [`Size::font_size`] does for buttons.
+pub fn toggle<S: Display + 'static>(
+    on: RwSignal<bool>,
+    text: impl Fn() -> S + 'static,
+    variant: Variant,
+    size: Size,
+) -> Stack {
+    h_stack((
+        toggle_button(move || on.get())
+            .on_toggle(set_on(on))
+            .themed(move |p, s| s.apply(toggle_track_style(p, variant, on.get()))),
+        label(text),
+    ))
+    .themed_toggle(variant)
+    .themed(move |p, s| s.font_size(size.font_size(p)))
+}
+
+/// Toggle handler that stores the switch's new state in `on`.
+pub(crate) fn set_on(on: RwSignal<bool>) -> impl Fn(bool) + 'static {
+    move |v| on.set(v)
+}
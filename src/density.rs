@@ -0,0 +1,65 @@
+//! Layout density preference
+
+use floem_reactive::{RwSignal, Scope, SignalGet, SignalUpdate};
+use serde::{Deserialize, Serialize};
+
+use crate::scope::ThemeScope;
+
+/// How tightly recipes pack their content.
+///
+/// Density scales the padding and gap tokens of every resolved palette, and
+/// through them `Size::padding` and the recipes built on it. Radii, borders,
+/// and font sizes are left alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum Density {
+    /// Tighter spacing for data-heavy screens.
+    Compact,
+    /// The spacing the palettes are designed with.
+    #[default]
+    Comfortable,
+    /// Roomier spacing for onboarding and touch-friendly screens.
+    Spacious,
+}
+
+impl Density {
+    /// Multiplier applied to padding and gap tokens.
+    pub fn factor(self) -> f32 {
+        match self {
+            Density::Compact => 0.75,
+            Density::Comfortable => 1.0,
+            Density::Spacious => 1.25,
+        }
+    }
+}
+
+thread_local! {
+    /// Owned by its own root scope so it outlives whichever scope happens to
+    /// touch it first.
+    static DENSITY: RwSignal<Density> = Scope::new().create_rw_signal(Density::default());
+}
+
+/// Set the app-wide density. Scopes without their own density follow it.
+///
+/// # Panics
+///
+/// Panics if called outside a reactive runtime.
+pub fn set_density(density: Density) {
+    DENSITY.with(|d| d.set(density));
+}
+
+/// The app-wide density (reactive).
+///
+/// # Panics
+///
+/// Panics if called outside a reactive runtime.
+pub fn density() -> Density {
+    DENSITY.with(|d| d.get())
+}
+
+/// The density in effect for an optional scope, falling back to the global one.
+pub(crate) fn density_in(scope: Option<ThemeScope>) -> Density {
+    match scope {
+        Some(scope) => scope.effective_density(),
+        None => density(),
+    }
+}
@@ -0,0 +1,99 @@
+//! View extension trait for applying recipes reactively
+
+use floem::style::Style;
+use floem::views::Decorators;
+
+use crate::global::{global_style, unthemed_style};
+use crate::palette::Palette;
+use crate::scope::{current_scope, in_scope};
+use crate::styles;
+use crate::theme::palette;
+use crate::variant::{Fill, Size, Variant};
+
+/// Extension methods that apply fleem recipes to any floem view.
+///
+/// Each method adds a `.style()` layer that reads the palette, so the view
+/// restyles when the appearance changes. Styles added before or after are
+/// kept, and later layers win where they set the same property:
+///
+/// ```rust,no_run
+/// use floem::views::{button, Decorators};
+/// use fleem::{Fill, Size, ThemedExt, Variant};
+///
+/// let _save = button("Save")
+///     .themed_button(Variant::Primary, Size::Normal, Fill::Filled)
+///     .style(|s| s.min_width(80.0));
+/// ```
+pub trait ThemedExt: Decorators {
+    /// Apply a style computed from the current palette.
+    ///
+    /// The palette comes from [`palette()`] in the [`crate::ThemeScope`]
+    /// enclosing the view when it is built. That scope is also the current
+    /// one while `style` runs, so cached recipes called inside it match.
+    fn themed(self, style: impl Fn(&Palette, Style) -> Style + 'static) -> Self::DV {
+        let scope = current_scope();
+        self.style(move |s| in_scope(scope, || style(&palette(), s)))
+    }
+
+    /// Apply [`styles::button_style`].
+    fn themed_button(self, variant: Variant, size: Size, fill: Fill) -> Self::DV {
+        self.themed(move |p, s| s.apply(styles::button_style(p, variant, size, fill)))
+    }
+
+    /// Apply [`styles::icon_button_style`].
+    fn themed_icon_button(self, variant: Variant, size: Size, fill: Fill) -> Self::DV {
+        self.themed(move |p, s| s.apply(styles::icon_button_style(p, variant, size, fill)))
+    }
+
+    /// Apply [`styles::card_style`].
+    fn themed_card(self) -> Self::DV {
+        self.themed(|p, s| s.apply(styles::card_style(p)))
+    }
+
+    /// Apply [`styles::input_style`].
+    fn themed_input(self) -> Self::DV {
+        self.themed(|p, s| s.apply(styles::input_style(p)))
+    }
+
+    /// Apply [`styles::badge_style`].
+    fn themed_badge(self, variant: Variant) -> Self::DV {
+        self.themed(move |p, s| s.apply(styles::badge_style(p, variant)))
+    }
+
+    /// Apply [`styles::badge_fill_style`].
+    fn themed_badge_fill(self, variant: Variant, fill: Fill) -> Self::DV {
+        self.themed(move |p, s| s.apply(styles::badge_fill_style(p, variant, fill)))
+    }
+
+    /// Apply [`styles::chip_style`].
+    fn themed_chip(self, variant: Variant, fill: Fill) -> Self::DV {
+        self.themed(move |p, s| s.apply(styles::chip_style(p, variant, fill)))
+    }
+
+    /// Apply [`styles::checkbox_style`].
+    fn themed_checkbox(self, variant: Variant) -> Self::DV {
+        self.themed(move |p, s| s.apply(styles::checkbox_style(p, variant)))
+    }
+
+    /// Apply [`styles::toggle_style`].
+    fn themed_toggle(self, variant: Variant) -> Self::DV {
+        self.themed(move |p, s| s.apply(styles::toggle_style(p, variant)))
+    }
+
+    /// Theme every stock floem widget below this view with [`global_style`].
+    ///
+    /// Call once on the window's root view.
+    fn themed_root(self) -> Self::DV {
+        self.themed(|p, s| s.apply(global_style(p)))
+    }
+
+    /// Opt this subtree out of [`Self::themed_root`] styling.
+    ///
+    /// Stock widgets below it get floem's plain look back; see
+    /// [`unthemed_style`].
+    fn unthemed(self) -> Self::DV {
+        self.style(|s| s.apply(unthemed_style()))
+    }
+}
+
+impl<V: Decorators> ThemedExt for V {}
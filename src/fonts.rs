@@ -0,0 +1,32 @@
+//! Registration of theme-bundled font files with floem's text system
+
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+use floem::text::FONT_SYSTEM;
+
+use crate::theme::ThemeDef;
+
+/// Addresses of font blobs already handed to the font database.
+static LOADED: OnceLock<Mutex<HashSet<usize>>> = OnceLock::new();
+
+/// Load the fonts bundled by `theme` into floem's font database.
+///
+/// Each blob is loaded once, however many scopes or windows use the theme.
+pub(crate) fn register(theme: &dyn ThemeDef) {
+    let fonts = theme.fonts();
+    if fonts.is_empty() {
+        return;
+    }
+
+    let mut loaded = LOADED
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    let mut font_system = FONT_SYSTEM.lock();
+    for data in fonts {
+        if loaded.insert(data.as_ptr() as usize) {
+            font_system.db_mut().load_font_data(data.to_vec());
+        }
+    }
+}
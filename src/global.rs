@@ -0,0 +1,168 @@
+//! Root-level style that themes floem's built-in widget classes
+
+use floem::prelude::Color;
+use floem::style::{CursorStyle, Style};
+use floem::views::scroll;
+use floem::views::{
+    ButtonClass, CheckboxClass, ListClass, ListItemClass, TextInputClass, ToggleButtonClass,
+};
+
+use crate::palette::Palette;
+use crate::styles;
+use crate::variant::{Fill, Size, Variant};
+
+/// Style that targets floem's built-in widget classes.
+///
+/// Applied to a root view, every stock `button`, `text_input`, `checkbox`,
+/// `toggle_button`, `list`, and scroll bar below it picks up fleem's recipes
+/// without a per-view `.style()`. Styles set on individual views still win.
+///
+/// Usually installed with [`crate::ThemedExt::themed_root`], which re-applies
+/// it when the theme or appearance changes.
+pub fn global_style(palette: &Palette) -> Style {
+    let c = &palette.colors;
+    let sp = &palette.spacing;
+
+    Style::new()
+        .class(ButtonClass, |s| {
+            s.apply(styles::button_style(
+                palette,
+                Variant::Secondary,
+                Size::Normal,
+                Fill::Filled,
+            ))
+        })
+        .class(TextInputClass, |s| s.apply(styles::input_style(palette)))
+        .class(CheckboxClass, |s| {
+            s.background(c.bg_input)
+                .border(sp.border_width)
+                .border_color(c.border_strong)
+                .border_radius(palette.shape.radius(sp.radius_sm))
+                .color(c.accent)
+                .hover(|s| s.background(c.bg_hover))
+                .focus(|s| s.border_color(c.border_focus))
+        })
+        .class(ToggleButtonClass, |s| {
+            s.background(c.border_strong)
+                .color(c.text_primary)
+                .selected(|s| s.background(c.accent))
+        })
+        .class(ListClass, |s| {
+            s.background(c.bg_surface).color(c.text_primary)
+        })
+        .class(ListItemClass, |s| {
+            s.padding_vert(sp.pad_xs)
+                .padding_horiz(sp.pad_md)
+                .border_radius(sp.radius_sm)
+                .hover(|s| s.background(c.bg_hover))
+                .selected(|s| s.background(c.bg_selected))
+        })
+        .class(scroll::Handle, |s| {
+            s.background(c.border_strong)
+                .border_radius(sp.radius_md)
+                .hover(|s| s.background(c.text_muted))
+        })
+        .class(scroll::Track, |s| s.background(Color::TRANSPARENT))
+}
+
+/// Style that opts a subtree out of [`global_style`].
+///
+/// Class styles cascade and can only be overridden, not removed, so this
+/// re-applies a stock look modeled on floem's built-in widget styles to every
+/// class `global_style` targets: visible boxes, borders, and tracks in
+/// neutral grays, black text, and floem's default font size and cursors.
+/// Widgets in the subtree look as they would without fleem.
+pub fn unthemed_style() -> Style {
+    Style::new()
+        .class(ButtonClass, |s| s.apply(stock_button()))
+        .class(TextInputClass, |s| s.apply(stock_input()))
+        .class(CheckboxClass, |s| s.apply(stock_checkbox()))
+        .class(ToggleButtonClass, |s| s.apply(stock_toggle()))
+        .class(ListClass, |s| s.apply(stock_list()))
+        .class(ListItemClass, |s| s.apply(stock_list_item()))
+        .class(scroll::Handle, |s| s.apply(stock_scroll_handle()))
+        .class(scroll::Track, |s| s.background(Color::TRANSPARENT))
+}
+
+// -- Stock widget look --------------------------------------------------------
+
+const STOCK_TEXT: Color = Color::BLACK;
+const STOCK_FONT_SIZE: f32 = 14.0;
+const STOCK_PADDING: f32 = 5.0;
+const STOCK_RADIUS: f32 = 5.0;
+const STOCK_BORDER: Color = Color::rgb8(140, 140, 140);
+const STOCK_FOCUS: Color = Color::rgb8(114, 74, 140);
+const STOCK_BG: Color = Color::rgb8(240, 240, 240);
+const STOCK_HOVER: Color = Color::rgb8(228, 237, 216);
+const STOCK_ACTIVE: Color = Color::rgb8(160, 160, 160);
+const STOCK_DISABLED_BG: Color = Color::rgb8(230, 230, 230);
+const STOCK_DISABLED_TEXT: Color = Color::rgb8(128, 128, 128);
+
+/// Text, font size, and cursor shared by the stock widgets.
+fn stock_text(s: Style, cursor: CursorStyle) -> Style {
+    s.color(STOCK_TEXT)
+        .font_size(STOCK_FONT_SIZE)
+        .cursor(cursor)
+        .disabled(|s| s.color(STOCK_DISABLED_TEXT))
+}
+
+/// A bordered, rounded box with hover, focus, and disabled states.
+fn stock_box(s: Style, background: Color) -> Style {
+    s.background(background)
+        .border(1.0)
+        .border_color(STOCK_BORDER)
+        .border_radius(STOCK_RADIUS)
+        .hover(|s| s.background(STOCK_HOVER).border_color(STOCK_BORDER))
+        .focus(|s| s.border(1.0).border_color(STOCK_FOCUS))
+        .disabled(|s| s.background(STOCK_DISABLED_BG).border_color(STOCK_BORDER))
+}
+
+fn stock_button() -> Style {
+    let s = stock_box(Style::new(), STOCK_BG)
+        .padding(STOCK_PADDING)
+        .active(|s| s.background(STOCK_ACTIVE));
+    stock_text(s, CursorStyle::Default)
+}
+
+fn stock_input() -> Style {
+    let s = stock_box(Style::new(), Color::WHITE)
+        .padding(STOCK_PADDING)
+        .hover(|s| s.background(Color::WHITE));
+    stock_text(s, CursorStyle::Text)
+}
+
+pub(crate) fn stock_checkbox() -> Style {
+    let s = stock_box(Style::new(), Color::WHITE).padding(0.0);
+    stock_text(s, CursorStyle::Default)
+}
+
+pub(crate) fn stock_toggle() -> Style {
+    let s = Style::new()
+        .background(STOCK_ACTIVE)
+        .border(0.0)
+        .border_radius(STOCK_RADIUS)
+        .padding(0.0)
+        .hover(|s| s.background(STOCK_ACTIVE))
+        .selected(|s| s.background(STOCK_FOCUS));
+    stock_text(s, CursorStyle::Default).color(Color::WHITE)
+}
+
+fn stock_list() -> Style {
+    stock_text(Style::new(), CursorStyle::Default).background(Color::TRANSPARENT)
+}
+
+fn stock_list_item() -> Style {
+    Style::new()
+        .padding(0.0)
+        .border_radius(0.0)
+        .background(Color::TRANSPARENT)
+        .hover(|s| s.background(STOCK_HOVER))
+        .selected(|s| s.background(STOCK_ACTIVE))
+}
+
+fn stock_scroll_handle() -> Style {
+    Style::new()
+        .background(Color::rgba8(166, 166, 166, 140))
+        .border_radius(4.0)
+        .hover(|s| s.background(Color::rgb8(166, 166, 166)))
+}
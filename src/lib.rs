@@ -10,19 +10,49 @@
 //! // Initialize once at startup:
 //! let _appearance = init(Appearance::System);
 //!
-//! // Then use palette() + style recipes in your views.
+//! // Then use palette() + style recipes in your views, or the
+//! // `ThemedExt` shorthands like `.themed_button(...)`.
 //! ```
 
+mod blend;
+mod cache;
+pub mod components;
+mod density;
+mod ext;
+mod fonts;
+mod global;
+mod motion;
 mod palette;
+mod scale;
+mod schedule;
+mod scope;
 pub mod styles;
 mod system;
 mod theme;
 pub mod tokens;
+mod transition;
 mod variant;
+mod window;
 
+pub use blend::lerp_color;
+pub use cache::{cached_badge_style, cached_button_style, cached_style, clear_style_cache, Recipe};
+pub use density::{density, set_density, Density};
+pub use ext::ThemedExt;
+pub use global::{global_style, unthemed_style};
+pub use motion::{follow_system_reduced_motion, reduced_motion, set_reduced_motion};
 pub use palette::Palette;
+pub use scale::{set_ui_scale, ui_scale, UiScale};
+pub use schedule::{set_clock, time_of_day, Clock, TimeOfDay};
+pub use scope::{current_scope, theme_scope, ThemeScope};
 pub use theme::{appearance_signal, init, init_with, palette, Appearance, ResolvedAppearance, ThemeDef};
-pub use variant::{Fill, Size, Variant, VariantColors};
+pub use transition::{palette_transition, set_palette_transition};
+pub use variant::{
+    CustomVariant, CustomVariantId, Fill, Shape, Size, Variant, VariantColors, VariantTokens,
+};
+pub use window::{
+    forget_window, set_window_appearance, set_window_theme, window_palette, window_scope,
+    with_window,
+};
 
 #[cfg(test)]
 mod tests {
@@ -53,7 +83,7 @@ mod tests {
             Variant::Ghost,
             Variant::Link,
         ];
-        let fills = [Fill::Filled, Fill::Outlined];
+        let fills = [Fill::Filled, Fill::Outlined, Fill::Soft, Fill::Dashed];
 
         for variant in &variants {
             for fill in &fills {
@@ -87,10 +117,12 @@ mod tests {
         let small = Size::Small.font_size(&palette);
         let normal = Size::Normal.font_size(&palette);
         let large = Size::Large.font_size(&palette);
+        let xlarge = Size::XLarge.font_size(&palette);
 
         assert!(tiny < small, "Tiny ({tiny}) should be < Small ({small})");
         assert!(small < normal, "Small ({small}) should be < Normal ({normal})");
         assert!(normal < large, "Normal ({normal}) should be < Large ({large})");
+        assert!(large < xlarge, "Large ({large}) should be < XLarge ({xlarge})");
     }
 
     #[test]
@@ -100,20 +132,37 @@ mod tests {
         let (sv, sh) = Size::Small.padding(&palette);
         let (nv, nh) = Size::Normal.padding(&palette);
         let (lv, lh) = Size::Large.padding(&palette);
+        let (xv, xh) = Size::XLarge.padding(&palette);
 
         assert!(tv < sv && th < sh, "Tiny padding should be < Small");
         assert!(sv < nv && sh < nh, "Small padding should be < Normal");
         assert!(nv < lv && nh < lh, "Normal padding should be < Large");
+        assert!(lv < xv && lh < xh, "Large padding should be < XLarge");
     }
 
     #[test]
     fn appearance_serde_roundtrip() {
-        let values = [Appearance::Dark, Appearance::Light, Appearance::System];
+        let values = [
+            Appearance::Dark,
+            Appearance::Light,
+            Appearance::System,
+            Appearance::Scheduled {
+                light_from: TimeOfDay::new(7, 0),
+                dark_from: TimeOfDay::new(19, 30),
+            },
+        ];
         for value in &values {
             let json = serde_json::to_string(value).unwrap();
             let back: Appearance = serde_json::from_str(&json).unwrap();
             assert_eq!(*value, back);
         }
+
+        // Times outside a day are rejected rather than stored as-is.
+        let time: TimeOfDay = serde_json::from_str(r#"{"hour":23,"minute":59}"#).unwrap();
+        assert_eq!(time, TimeOfDay::new(23, 59));
+        for json in [r#"{"hour":30,"minute":0}"#, r#"{"hour":7,"minute":60}"#] {
+            assert!(serde_json::from_str::<TimeOfDay>(json).is_err(), "{json}");
+        }
     }
 
     #[test]
@@ -130,13 +179,14 @@ mod tests {
             Variant::Ghost,
             Variant::Link,
         ];
-        let sizes = [Size::Large, Size::Normal, Size::Small, Size::Tiny];
-        let fills = [Fill::Filled, Fill::Outlined];
+        let sizes = [Size::XLarge, Size::Large, Size::Normal, Size::Small, Size::Tiny];
+        let fills = [Fill::Filled, Fill::Outlined, Fill::Soft, Fill::Dashed];
 
         for variant in &variants {
             for size in &sizes {
                 for fill in &fills {
                     let _ = styles::button_style(&palette, *variant, *size, *fill);
+                    let _ = styles::icon_button_style(&palette, *variant, *size, *fill);
                 }
             }
         }
@@ -148,15 +198,833 @@ mod tests {
         let _ = styles::progress_fill_style(&palette, 50.0);
         let _ = styles::modal_backdrop_style(&palette, true);
         let _ = styles::modal_backdrop_style(&palette, false);
+        let _ = styles::modal_backdrop_style_at(&palette, true, 2);
         let _ = styles::modal_card_style(&palette);
 
         for variant in &variants {
             let _ = styles::badge_style(&palette, *variant);
             let _ = styles::checkbox_style(&palette, *variant);
             let _ = styles::toggle_style(&palette, *variant);
+            let _ = styles::toggle_track_style(&palette, *variant, true);
+            let _ = styles::toggle_track_style(&palette, *variant, false);
+            for fill in &fills {
+                let _ = styles::badge_fill_style(&palette, *variant, *fill);
+                let _ = styles::chip_style(&palette, *variant, *fill);
+            }
         }
 
         let _ = styles::status_badge_style(&palette, true);
         let _ = styles::status_badge_style(&palette, false);
+
+        let _ = styles::title_text_style(&palette);
+        let _ = styles::heading_text_style(&palette);
+        let _ = styles::body_text_style(&palette);
+        let _ = styles::caption_text_style(&palette);
+        let _ = styles::code_text_style(&palette);
+        let _ = styles::menu_style(&palette);
+        let _ = styles::menu_item_style(&palette);
+        let _ = styles::tooltip_style(&palette);
+        for level in 0..=5 {
+            let _ = styles::elevation_style(&palette, level);
+        }
+
+        let _ = global_style(&palette);
+        let _ = unthemed_style();
+    }
+
+    #[test]
+    fn unthemed_widgets_keep_a_visible_box() {
+        use floem::peniko::Brush;
+        use floem::style::Background;
+
+        let stock = [("checkbox", global::stock_checkbox()), ("toggle", global::stock_toggle())];
+        for (name, style) in stock {
+            match style.get(Background) {
+                Some(Brush::Solid(bg)) => assert!(bg.a > 0, "unthemed {name} is transparent"),
+                other => panic!("unthemed {name} has background {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn components_take_variant_size_and_fill() {
+        use floem_reactive::{RwSignal, SignalGet};
+
+        let on = RwSignal::new(false);
+        let text = RwSignal::new(String::new());
+        for size in [Size::Small, Size::XLarge] {
+            let _ = components::badge(|| "New", Variant::Success, size, Fill::Outlined);
+            let _ = components::chip(|| "Tag", Variant::Info, size, Fill::Soft, move || on.get());
+            let _ = components::text_input(text, Variant::Error, size);
+            let _ = components::checkbox(on, || "Agree", Variant::Primary, size);
+            let _ = components::toggle(on, || "Notify", Variant::Success, size);
+            let _ = components::button("Go", Variant::Primary, size, Fill::Dashed);
+        }
+        assert!(!on.get());
+    }
+
+    #[test]
+    fn toggle_writes_its_signal() {
+        use floem_reactive::{RwSignal, SignalGet};
+
+        let on = RwSignal::new(false);
+        let set_on = components::toggle::set_on(on);
+        set_on(true);
+        assert!(on.get());
+        set_on(false);
+        assert!(!on.get());
+    }
+
+    #[test]
+    fn badge_text_meets_contrast() {
+        use floem::style::{Background, TextColor};
+        let variants = [
+            Variant::Primary,
+            Variant::Secondary,
+            Variant::Success,
+            Variant::Warning,
+            Variant::Error,
+            Variant::Info,
+            Variant::Neutral,
+            Variant::Ghost,
+            Variant::Link,
+        ];
+        for palette in [Palette::dark(), Palette::light()] {
+            for variant in variants {
+                let style = styles::badge_style(&palette, variant);
+                let Some(floem::peniko::Brush::Solid(bg)) = style.get(Background) else {
+                    panic!("{variant:?} badge has no background");
+                };
+                let fg = style.get(TextColor).unwrap();
+                let ratio = blend::contrast_ratio(fg, bg);
+                assert!(ratio >= 4.5, "{variant:?} badge text is {ratio:.2}:1");
+            }
+        }
+    }
+
+    #[test]
+    fn style_cache_reuses_entries() {
+        clear_style_cache();
+        let _ = cached_button_style(Variant::Primary, Size::Normal, Fill::Filled);
+        let _ = cached_button_style(Variant::Primary, Size::Normal, Fill::Filled);
+        assert_eq!(cache::cached_len(), 1);
+
+        // Parameters a recipe ignores must not create separate entries.
+        let _ = cached_style(Recipe::Card, Variant::Error, Size::Large, Fill::Outlined);
+        let _ = cached_style(Recipe::Card, Variant::Info, Size::Tiny, Fill::Filled);
+        assert_eq!(cache::cached_len(), 2);
+
+        clear_style_cache();
+        assert_eq!(cache::cached_len(), 0);
+    }
+
+    #[test]
+    fn style_cache_keeps_entries_per_scope() {
+        clear_style_cache();
+        let light = ThemeScope::new().appearance(Appearance::Light);
+        let compact = ThemeScope::new().density(Density::Compact);
+        let style = || cached_button_style(Variant::Primary, Size::Normal, Fill::Filled);
+
+        // Alternating between scopes that resolve differently must not evict.
+        for _ in 0..3 {
+            let _ = theme_scope(light, style);
+            let _ = theme_scope(compact, style);
+            let _ = style();
+        }
+        assert_eq!(cache::cached_len(), 3);
+        clear_style_cache();
+    }
+
+    #[test]
+    fn nested_modals_stack_above_every_open_modal() {
+        use components::modal::{push_modal, remove_modal};
+
+        let (a, b, c) = (1001, 1002, 1003);
+        assert_eq!(push_modal(a), 0);
+        assert_eq!(push_modal(b), 1);
+        // Closing the bottom modal must not let a new one sink to B's level.
+        remove_modal(a);
+        let c_depth = push_modal(c);
+        assert!(c_depth > 1, "C at depth {c_depth} is not above B");
+        remove_modal(b);
+        remove_modal(c);
+        assert_eq!(push_modal(a), 0);
+        remove_modal(a);
+    }
+
+    #[test]
+    fn modal_tab_order_wraps_around() {
+        use components::modal::next_focus;
+
+        assert_eq!(next_focus(Some(0), 3, false), 1);
+        assert_eq!(next_focus(Some(2), 3, false), 0);
+        assert_eq!(next_focus(Some(0), 3, true), 2);
+        assert_eq!(next_focus(Some(1), 3, true), 0);
+        // Focus outside the order enters it at either end.
+        assert_eq!(next_focus(None, 3, false), 0);
+        assert_eq!(next_focus(None, 3, true), 2);
+        assert_eq!(next_focus(Some(0), 1, true), 0);
+    }
+
+    #[test]
+    fn theme_scope_overrides_appearance() {
+        let light = Palette::light().colors.bg_base;
+        let scope = ThemeScope::new().appearance(Appearance::Light);
+
+        let inside = theme_scope(scope, || {
+            // Nested scopes inherit unset fields from the enclosing one.
+            let nested = ThemeScope::new();
+            (palette(), nested.palette())
+        });
+        assert_eq!(inside.0.colors.bg_base, light);
+        assert_eq!(inside.1.colors.bg_base, light);
+        assert_eq!(scope.palette().colors.bg_base, light);
+
+        // Outside the scope, the global (uninitialized: dark) palette applies.
+        assert_eq!(palette().colors.bg_base, Palette::dark().colors.bg_base);
+    }
+
+    #[test]
+    fn themed_restyles_after_appearance_change() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        let seen = Rc::new(Cell::new(Color::TRANSPARENT));
+        let scope = ThemeScope::new().appearance(Appearance::Dark);
+        let _view = theme_scope(scope, || {
+            let seen = seen.clone();
+            floem::views::empty().themed(move |p, s| {
+                seen.set(p.colors.bg_base);
+                s
+            })
+        });
+        assert_eq!(seen.get(), Palette::dark().colors.bg_base);
+
+        scope.set_appearance(Some(Appearance::Light));
+        assert_eq!(seen.get(), Palette::light().colors.bg_base);
+    }
+
+    #[test]
+    fn windows_resolve_their_own_appearance() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        use floem::window::WindowId;
+
+        let (light, dark) = (Palette::light().colors.bg_base, Palette::dark().colors.bg_base);
+        let (a, b) = (WindowId::from(1), WindowId::from(2));
+        set_window_appearance(a, Some(Appearance::Light));
+        set_window_appearance(b, Some(Appearance::Dark));
+        assert_eq!(window_palette(a).colors.bg_base, light);
+        assert_eq!(window_palette(b).colors.bg_base, dark);
+
+        // Building a second window's root doesn't take over the first's
+        // scope, also when their style closures re-run later.
+        let root = |id| {
+            let seen = Rc::new(Cell::new(Color::TRANSPARENT));
+            let view = with_window(id, || {
+                let seen = seen.clone();
+                floem::views::empty().themed(move |p, s| {
+                    seen.set(p.colors.bg_base);
+                    s
+                })
+            });
+            (view, seen)
+        };
+        let (_root_a, seen_a) = root(a);
+        let (_root_b, seen_b) = root(b);
+        assert_eq!((seen_a.get(), seen_b.get()), (light, dark));
+        assert_eq!(palette().colors.bg_base, dark);
+
+        set_window_appearance(a, Some(Appearance::Dark));
+        set_window_appearance(b, Some(Appearance::Light));
+        assert_eq!((seen_a.get(), seen_b.get()), (dark, light));
+        set_window_appearance(a, Some(Appearance::Light));
+
+        // A forgotten window follows the global appearance again.
+        forget_window(a);
+        assert_eq!(window_palette(a).colors.bg_base, dark);
+        forget_window(a);
+        forget_window(b);
+    }
+
+    #[test]
+    fn reduced_motion_skips_palette_transitions() {
+        use std::time::Duration;
+
+        set_palette_transition(Some(Duration::from_millis(200)));
+        set_reduced_motion(true);
+
+        let dark = (0, ResolvedAppearance::Dark);
+        let light = (0, ResolvedAppearance::Light);
+        let _ = transition::apply(99, dark, Palette::dark());
+        let p = transition::apply(99, light, Palette::light());
+        assert_eq!(p.colors.bg_base, Palette::light().colors.bg_base);
+        assert!(!transition::in_progress());
+
+        follow_system_reduced_motion();
+        set_palette_transition(None);
+    }
+
+    #[test]
+    fn palette_transition_interpolates_then_stops() {
+        use std::time::Duration;
+
+        set_reduced_motion(false);
+        set_palette_transition(Some(Duration::from_millis(200)));
+        let (dark, light) = (Palette::dark().colors.bg_base, Palette::light().colors.bg_base);
+
+        let scope = ThemeScope::new().appearance(Appearance::Dark);
+        assert_eq!(theme_scope(scope, palette).colors.bg_base, dark);
+        scope.set_appearance(Some(Appearance::Light));
+        let _ = theme_scope(scope, palette);
+        assert!(transition::in_progress() && transition::ticking());
+
+        transition::advance_clock(Duration::from_millis(100));
+        let mid = theme_scope(scope, palette).colors.bg_base;
+        assert_ne!(mid, dark);
+        assert_ne!(mid, light);
+        assert!(transition::frame(), "ticker stopped mid-transition");
+
+        transition::advance_clock(Duration::from_millis(150));
+        assert!(!transition::frame(), "ticker kept running");
+        assert!(!transition::ticking());
+        assert_eq!(theme_scope(scope, palette).colors.bg_base, light);
+
+        set_palette_transition(None);
+        follow_system_reduced_motion();
+    }
+
+    #[test]
+    fn palette_lerp_endpoints_and_midpoint() {
+        let dark = Palette::dark();
+        let light = Palette::light();
+
+        let start = Palette::lerp(&dark, &light, 0.0);
+        let end = Palette::lerp(&dark, &light, 1.0);
+        assert_eq!(start.colors.bg_base, dark.colors.bg_base);
+        assert_eq!(end.colors.bg_base, light.colors.bg_base);
+
+        // Out-of-range t is clamped.
+        let past = Palette::lerp(&dark, &light, 2.0);
+        assert_eq!(past.colors.accent, light.colors.accent);
+
+        // Gray endpoints stay gray, between the two in lightness.
+        let mid = lerp_color(Color::BLACK, Color::WHITE, 0.5);
+        assert_eq!(mid.r, mid.g);
+        assert_eq!(mid.g, mid.b);
+        assert!(mid.r > 0 && mid.r < 255);
+
+        // Fading from transparent keeps the opaque color's hue.
+        let fade = lerp_color(Color::TRANSPARENT, Color::WHITE, 0.5);
+        assert_eq!((fade.r, fade.g, fade.b), (255, 255, 255));
+    }
+
+    #[test]
+    fn typography_lerp_takes_nearer_font_family() {
+        let mut a = Palette::dark().typography;
+        let mut b = a;
+        a.font_mono = "Menlo";
+        b.font_mono = "JetBrains Mono";
+        b.font_body = 16.0;
+
+        assert_eq!(tokens::TypographyTokens::lerp(&a, &b, 0.4).font_mono, "Menlo");
+        assert_eq!(tokens::TypographyTokens::lerp(&a, &b, 0.6).font_mono, "JetBrains Mono");
+        assert_eq!(tokens::TypographyTokens::lerp(&a, &b, 0.5).font_body, 14.0);
+
+        b.weight_title = floem::text::Weight::BLACK;
+        b.line_height_normal = 1.6;
+        let mid = tokens::TypographyTokens::lerp(&a, &b, 0.5);
+        assert_eq!(mid.weight_title, floem::text::Weight::BLACK);
+        assert!(mid.line_height_normal > a.line_height_normal);
+
+        b.letter_spacing_caption = 0.6;
+        let mid = tokens::TypographyTokens::lerp(&a, &b, 0.5);
+        assert!((mid.letter_spacing_caption - 0.4).abs() < 1e-6);
+        assert_eq!(a.scaled(2.0).letter_spacing_title, -0.4);
+    }
+
+    #[test]
+    fn scheduled_appearance_follows_clock() {
+        use std::rc::Rc;
+
+        let schedule = Appearance::Scheduled {
+            light_from: TimeOfDay::new(7, 0),
+            dark_from: TimeOfDay::new(19, 30),
+        };
+        assert_eq!(schedule.resolve_at(TimeOfDay::new(6, 59)), ResolvedAppearance::Dark);
+        assert_eq!(schedule.resolve_at(TimeOfDay::new(7, 0)), ResolvedAppearance::Light);
+        assert_eq!(schedule.resolve_at(TimeOfDay::new(19, 29)), ResolvedAppearance::Light);
+        assert_eq!(schedule.resolve_at(TimeOfDay::new(19, 30)), ResolvedAppearance::Dark);
+
+        // A light period that wraps past midnight.
+        let night_shift = Appearance::Scheduled {
+            light_from: TimeOfDay::new(22, 0),
+            dark_from: TimeOfDay::new(6, 0),
+        };
+        assert_eq!(night_shift.resolve_at(TimeOfDay::new(23, 0)), ResolvedAppearance::Light);
+        assert_eq!(night_shift.resolve_at(TimeOfDay::new(12, 0)), ResolvedAppearance::Dark);
+
+        set_clock(Some(Rc::new(|| TimeOfDay::new(12, 0))));
+        assert_eq!(time_of_day(), TimeOfDay::new(12, 0));
+        assert_eq!(schedule.resolve_at(time_of_day()), ResolvedAppearance::Light);
+        set_clock(None);
+    }
+
+    #[test]
+    fn reduced_motion_collapses_durations() {
+        let motion = Palette::dark().motion;
+        assert!(motion.duration_fast < motion.duration_normal);
+        assert!(motion.duration_normal < motion.duration_slow);
+        assert!(motion.fast().is_some());
+
+        let reduced = motion.reduced();
+        assert!(reduced.duration_slow.is_zero());
+        assert!(reduced.fast().is_none());
+        assert!(reduced.slow().is_none());
+    }
+
+    #[test]
+    fn reduced_motion_restyles_at_runtime() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        set_reduced_motion(false);
+        let animated = Rc::new(Cell::new(false));
+        floem_reactive::create_effect({
+            let animated = animated.clone();
+            move |_| animated.set(palette().motion.fast().is_some())
+        });
+        assert!(animated.get());
+
+        set_reduced_motion(true);
+        assert!(!animated.get());
+        follow_system_reduced_motion();
+    }
+
+    #[test]
+    fn elevation_levels_rise() {
+        for palette in [Palette::dark(), Palette::light()] {
+            assert!(!palette.elevation(0).is_visible());
+            for level in 1..tokens::ElevationTokens::MAX_LEVEL {
+                let low = palette.elevation(level);
+                let high = palette.elevation(level + 1);
+                assert!(low.blur < high.blur, "level {level} should blur less");
+                assert!(low.offset_y <= high.offset_y);
+            }
+            // Levels above the maximum clamp to it.
+            assert_eq!(palette.elevation(9), palette.elevation(4));
+        }
+        assert!(Palette::dark().elevation(2).color.a > Palette::light().elevation(2).color.a);
+    }
+
+    #[test]
+    fn density_scales_padding_and_gaps() {
+        let base = Palette::dark().spacing;
+        let compact = base.with_density(Density::Compact);
+        let spacious = base.with_density(Density::Spacious);
+        assert_eq!(base.with_density(Density::Comfortable).pad_md, base.pad_md);
+        assert!(compact.pad_md < base.pad_md && base.pad_md < spacious.pad_md);
+        assert!(compact.gap_lg < base.gap_lg && base.gap_lg < spacious.gap_lg);
+        assert_eq!(compact.radius_lg, base.radius_lg);
+        assert_eq!(spacious.border_width, base.border_width);
+
+        let scope = ThemeScope::new().density(Density::Compact);
+        let global = palette();
+        let scoped = theme_scope(scope, palette);
+        assert!(scoped.spacing.pad_lg < global.spacing.pad_lg);
+        assert!(Size::Normal.padding(&scoped).0 < Size::Normal.padding(&global).0);
+
+        set_density(Density::Spacious);
+        assert!(palette().spacing.pad_lg > global.spacing.pad_lg);
+        set_density(Density::Comfortable);
+    }
+
+    #[test]
+    fn ui_scale_clamps_and_scales_tokens() {
+        assert_eq!(UiScale::new(0.1).factor(), UiScale::MIN);
+        assert_eq!(UiScale::new(f32::NAN), UiScale::NORMAL);
+        let json = serde_json::to_string(&UiScale::new(1.5)).unwrap();
+        assert_eq!(json, "1.5");
+        let loaded: UiScale = serde_json::from_str("99.0").unwrap();
+        assert_eq!(loaded.factor(), UiScale::MAX);
+
+        let base = palette();
+        set_ui_scale(2.0);
+        let big = palette();
+        assert_eq!(big.typography.font_body, base.typography.font_body * 2.0);
+        assert_eq!(big.spacing.pad_md, base.spacing.pad_md * 2.0);
+        assert_eq!(big.spacing.radius_lg, base.spacing.radius_lg * 2.0);
+        assert_eq!(big.spacing.border_width, base.spacing.border_width);
+        assert_eq!(big.elevation(2).blur, base.elevation(2).blur * 2.0);
+        assert_eq!(big.elevation(4).offset_y, base.elevation(4).offset_y * 2.0);
+        assert_eq!(big.modal_tokens().width, base.modal_tokens().width * 2.0);
+        set_ui_scale(UiScale::NORMAL);
+    }
+
+    #[test]
+    fn type_scale_generates_ordered_sizes() {
+        let default = Palette::dark().with_type_scale(tokens::TypeScale::default());
+        let built_in = Palette::dark().typography;
+        let t = default.typography;
+        for (generated, fixed) in [
+            (t.font_title, built_in.font_title),
+            (t.font_heading, built_in.font_heading),
+            (t.font_body, built_in.font_body),
+            (t.font_label, built_in.font_label),
+            (t.font_small, built_in.font_small),
+            (t.font_tiny, built_in.font_tiny),
+        ] {
+            assert!((generated - fixed).abs() < 0.75, "{generated} vs {fixed}");
+        }
+        assert_eq!(t.font_mono, built_in.font_mono);
+        assert_eq!(t.letter_spacing_title, built_in.letter_spacing_title);
+        assert_eq!(t.letter_spacing_caption, built_in.letter_spacing_caption);
+
+        let scale = tokens::TypeScale::new(16.0, tokens::TypeScale::MAJOR_THIRD);
+        let t = Palette::light().with_type_scale(scale).typography;
+        assert_eq!(t.font_body, 16.0);
+        assert_eq!(t.font_heading, 20.0);
+        assert!(t.font_title > t.font_heading && t.font_small > t.font_tiny);
+    }
+
+    #[test]
+    fn family_stack_appends_script_fallbacks() {
+        let mut t = Palette::dark().typography;
+        t.fallback_cjk = &["Noto Sans JP"];
+        t.fallback_arabic = &[];
+        t.fallback_emoji = &["Twemoji"];
+        assert_eq!(t.family_stack("Inter"), "Inter, Noto Sans JP, Twemoji");
+
+        t.fallback_cjk = &[];
+        t.fallback_emoji = &[];
+        assert_eq!(t.family_stack(t.font_mono), "monospace");
+    }
+
+    #[test]
+    fn pressed_state_differs_from_rest_and_hover() {
+        let variants = [
+            Variant::Primary,
+            Variant::Secondary,
+            Variant::Success,
+            Variant::Warning,
+            Variant::Error,
+            Variant::Info,
+            Variant::Neutral,
+            Variant::Ghost,
+        ];
+        for palette in [Palette::dark(), Palette::light()] {
+            for variant in variants {
+                for fill in [Fill::Filled, Fill::Outlined] {
+                    let vc = variant.resolve(fill, &palette);
+                    assert_ne!(vc.bg_active, vc.bg, "{variant:?} {fill:?} active == rest");
+                    assert_ne!(vc.bg_active, vc.bg_hover, "{variant:?} {fill:?} active == hover");
+                }
+            }
+        }
+        // Outlined primary fills with the accent on hover, so its text flips.
+        let outlined = Variant::Primary.resolve(Fill::Outlined, &Palette::dark());
+        assert_ne!(outlined.fg_hover, outlined.bg_hover);
+    }
+
+    #[test]
+    fn every_interactive_variant_changes_on_hover() {
+        let mut unset = Palette::dark();
+        unset.colors.success_hover = unset.colors.success;
+        unset.colors.error_hover = Color::TRANSPARENT;
+        unset.colors = unset.colors.with_derived_hovers();
+
+        let interactive = [
+            Variant::Primary,
+            Variant::Secondary,
+            Variant::Success,
+            Variant::Warning,
+            Variant::Error,
+            Variant::Info,
+            Variant::Neutral,
+            Variant::Ghost,
+        ];
+        let fills = [Fill::Filled, Fill::Outlined, Fill::Soft, Fill::Dashed];
+        for palette in [Palette::dark(), Palette::light(), unset] {
+            for variant in interactive {
+                for fill in fills {
+                    let vc = variant.resolve(fill, &palette);
+                    assert_ne!(vc.bg_hover, vc.bg, "{variant:?} {fill:?} has no hover change");
+                }
+            }
+        }
+
+        // Explicit hover colors are left alone.
+        let dark = Palette::dark().colors;
+        assert_eq!(dark.with_derived_hovers().info_hover, dark.info_hover);
+    }
+
+    #[test]
+    fn soft_and_dashed_fills() {
+        // Soft semantic fills put the semantic color itself on its tint.
+        for palette in [Palette::dark(), Palette::light()] {
+            let c = palette.colors;
+            for (variant, bg, fg) in [
+                (Variant::Success, c.success_bg, c.success),
+                (Variant::Warning, c.warning_bg, c.warning),
+                (Variant::Error, c.error_bg, c.error),
+                (Variant::Info, c.info_bg, c.info),
+            ] {
+                let soft = variant.resolve(Fill::Soft, &palette);
+                assert_eq!((soft.bg, soft.fg), (bg, fg), "{variant:?}");
+                assert_eq!(soft.border, Color::TRANSPARENT);
+            }
+        }
+
+        // Dashed strokes its border with dashes; the other fills draw solid.
+        use floem::style::BorderLeft;
+        assert!(!Fill::Dashed.stroke(1.0).dash_pattern.is_empty());
+        assert!(Fill::Outlined.stroke(1.0).dash_pattern.is_empty());
+        let dark = Palette::dark();
+        for fill in [Fill::Filled, Fill::Outlined, Fill::Soft, Fill::Dashed] {
+            for style in [
+                styles::button_style(&dark, Variant::Primary, Size::Normal, fill),
+                styles::badge_fill_style(&dark, Variant::Info, fill),
+                styles::chip_style(&dark, Variant::Info, fill),
+            ] {
+                let dashed = !style.get(BorderLeft).0.dash_pattern.is_empty();
+                assert_eq!(dashed, fill == Fill::Dashed);
+            }
+        }
+
+        // Dashed keeps the outline at rest and tints on hover.
+        let dashed = Variant::Primary.resolve(Fill::Dashed, &Palette::light());
+        let outlined = Variant::Primary.resolve(Fill::Outlined, &Palette::light());
+        assert_eq!((dashed.bg, dashed.border), (outlined.bg, outlined.border));
+        assert_eq!(dashed.fg_hover, dashed.fg);
+        assert_ne!(dashed.bg_hover, outlined.bg_hover);
+    }
+
+    #[test]
+    fn custom_variants_resolve_from_palette() {
+        const MISSING: CustomVariantId = CustomVariantId::new("missing");
+        let tokens = VariantTokens {
+            color: Color::rgb8(126, 87, 194),
+            hover: Color::rgb8(149, 117, 205),
+            bg: Color::rgb8(45, 35, 65),
+            fg: Color::WHITE,
+        };
+        // Names read at runtime, e.g. from a theme file, register too.
+        let name = String::from("premium");
+        let palette = Palette::dark().with_custom_variants([CustomVariant::new(&name, tokens)]);
+        let premium = Variant::Custom(CustomVariantId::new("premium"));
+
+        let filled = premium.resolve(Fill::Filled, &palette);
+        assert_eq!((filled.bg, filled.bg_hover), (tokens.color, tokens.hover));
+        assert_eq!(premium.resolve(Fill::Soft, &palette).bg, tokens.bg);
+        assert_eq!(premium.accent(&palette), tokens.color);
+
+        // Registering an equal list again reuses the stored one.
+        let again = Palette::light().with_custom_variants([CustomVariant::new("premium", tokens)]);
+        assert!(std::ptr::eq(again.custom_variants, palette.custom_variants));
+
+        // Unregistered ids fall back to Primary.
+        let fallback = Variant::Custom(MISSING).resolve(Fill::Filled, &palette);
+        assert_eq!(fallback.bg, Variant::Primary.resolve(Fill::Filled, &palette).bg);
+
+        for variant in [premium, Variant::Custom(MISSING)] {
+            let _ = styles::button_style(&palette, variant, Size::Normal, Fill::Outlined);
+            let _ = styles::badge_style(&palette, variant);
+            let _ = styles::checkbox_style(&palette, variant);
+            let _ = styles::toggle_style(&palette, variant);
+        }
+    }
+
+    #[test]
+    fn component_tokens_derive_and_override() {
+        let palette = Palette::dark();
+        let button = palette.button_tokens(Size::Small);
+        assert_eq!((button.pad_v, button.pad_h), Size::Small.padding(&palette));
+        assert_eq!(palette.card_tokens().padding, palette.spacing.pad_xl);
+        assert_eq!(palette.modal_tokens().elevation, 4);
+
+        let mut custom = palette;
+        custom.components.card = Some(tokens::CardTokensPatch {
+            radius: Some(0.0),
+            ..Default::default()
+        });
+        custom.components.button = Some(tokens::ButtonTokensPatch {
+            radius: Some(2.0),
+            pad_h: Some(20.0),
+            ..Default::default()
+        });
+        assert_eq!(custom.card_tokens().radius, 0.0);
+        assert_eq!(custom.card_tokens().padding, palette.spacing.pad_xl);
+        assert_eq!(custom.button_tokens(Size::Large).radius, 2.0);
+        // Other components keep deriving from the shared tokens.
+        assert_eq!(custom.input_tokens(), palette.input_tokens());
+
+        // Patches scale with density and UI scale like the shared tokens.
+        let resolved = custom.components.with_density(Density::Compact).scaled(2.0);
+        let button = resolved.button.unwrap();
+        assert_eq!((button.pad_h, button.radius), (Some(30.0), Some(4.0)));
+        assert_eq!(button.border_width, None);
+
+        // Overrides are data, so theme files can carry them.
+        let json = serde_json::to_string(&custom.components).unwrap();
+        let parsed: tokens::ComponentTokens = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, custom.components);
+        let partial: tokens::ComponentTokens =
+            serde_json::from_str(r#"{"badge": {"pad_h": 10.0}}"#).unwrap();
+        assert_eq!(partial.badge.unwrap().pad_h, Some(10.0));
+        assert_eq!(partial.card, None);
+
+        let _ = styles::card_style(&custom);
+        let _ = styles::button_style(&custom, Variant::Primary, Size::Large, Fill::Filled);
+    }
+
+    #[test]
+    fn color_spec_resolves_references() {
+        use tokens::{ColorSpec, TokenError};
+
+        let base = Palette::dark();
+        let spec = ColorSpec::new()
+            .set("accent", "#8e24aa")
+            .set("border_focus", "{accent}")
+            .set("text_link", "{ border_focus }")
+            .set("bg_overlay", "#00000080")
+            .with_default_aliases();
+        let colors = base.with_color_spec(&spec).unwrap().colors;
+        assert_eq!(colors.accent, Color::rgb8(0x8e, 0x24, 0xaa));
+        assert_eq!(colors.border_focus, colors.accent);
+        assert_eq!(colors.text_link, colors.accent);
+        assert_eq!(colors.info, colors.accent);
+        // References to tokens the spec doesn't set use the base value.
+        assert_eq!(colors.info_hover, base.colors.accent_hover);
+        assert_eq!(colors.bg_overlay, Color::rgba8(0, 0, 0, 0x80));
+
+        // The built-in palettes already satisfy the default aliases.
+        for palette in [Palette::dark(), Palette::light()] {
+            let aliased = palette.with_color_spec(&ColorSpec::new().with_default_aliases());
+            assert_eq!(aliased.unwrap().colors.info, palette.colors.info);
+        }
+
+        let cycle = ColorSpec::new()
+            .set("accent", "{info}")
+            .set("info", "{border_focus}")
+            .set("border_focus", "{accent}");
+        let Err(TokenError::Cycle { path }) = cycle.resolve(&base.colors) else {
+            panic!("cycle not detected");
+        };
+        assert_eq!(path.first(), path.last());
+        assert_eq!(path.len(), 4);
+
+        let typo = ColorSpec::new().set("acent", "#ffffff");
+        assert!(matches!(typo.resolve(&base.colors), Err(TokenError::UnknownToken { .. })));
+        let dangling = ColorSpec::new().set("info", "{acent}");
+        let err = dangling.resolve(&base.colors).unwrap_err();
+        assert_eq!(err.to_string(), "`info` references unknown token `{acent}`");
+        let bad = ColorSpec::new().set("info", "blue");
+        assert!(matches!(bad.resolve(&base.colors), Err(TokenError::InvalidColor { .. })));
+
+        let json: ColorSpec = serde_json::from_str(r#"{"info": "{accent}"}"#).unwrap();
+        assert_eq!(json, ColorSpec::new().set("info", "{accent}"));
+        assert_eq!(tokens::ColorTokens::NAMES.len(), 39);
+    }
+
+    #[test]
+    fn shape_controls_control_radii() {
+        let rounded = Palette::dark();
+        let square = rounded.with_shape(Shape::Square);
+        let pill = rounded.with_shape(Shape::Pill);
+
+        for size in [Size::XLarge, Size::Large, Size::Normal, Size::Small, Size::Tiny] {
+            assert_eq!(size.radius(&square), 0.0);
+            assert_eq!(size.radius(&pill), Shape::PILL_RADIUS);
+            assert!(size.radius(&rounded) > 0.0);
+            assert_eq!(square.button_tokens(size).radius, 0.0);
+        }
+        assert_eq!(square.input_tokens().radius, 0.0);
+        assert_eq!(pill.badge_tokens().radius, Shape::PILL_RADIUS);
+        assert_eq!(rounded.badge_tokens().radius, rounded.spacing.radius_md);
+        // Cards keep their own radius.
+        assert_eq!(square.card_tokens().radius, rounded.card_tokens().radius);
+
+        // A shape passed per call wins over the palette's.
+        for size in [Size::XLarge, Size::Large, Size::Normal, Size::Small, Size::Tiny] {
+            let pill_button = rounded.button_tokens_shaped(size, Shape::Pill);
+            assert_eq!(pill_button.radius, Shape::PILL_RADIUS);
+            let rounded_button = square.button_tokens_shaped(size, Shape::Rounded);
+            assert_eq!(rounded_button.radius, rounded.button_tokens(size).radius);
+        }
+        assert_eq!(square.input_tokens_shaped(Shape::Rounded), rounded.input_tokens());
+        assert_eq!(rounded.badge_tokens_shaped(Shape::Square).radius, 0.0);
+
+        // A patched radius is the rounded one; the shape still applies.
+        let mut patched = square;
+        patched.components.badge = Some(tokens::BadgeTokensPatch {
+            radius: Some(3.0),
+            ..Default::default()
+        });
+        assert_eq!(patched.badge_tokens().radius, 0.0);
+        assert_eq!(patched.badge_tokens_shaped(Shape::Rounded).radius, 3.0);
+
+        for shape in [Shape::Square, Shape::Rounded, Shape::Pill] {
+            let (v, size, fill) = (Variant::Primary, Size::Normal, Fill::Filled);
+            let _ = styles::button_style_shaped(&rounded, v, size, fill, shape);
+            let _ = styles::icon_button_style_shaped(&rounded, v, size, fill, shape);
+            let _ = styles::badge_fill_style_shaped(&rounded, v, Fill::Soft, shape);
+            let _ = styles::input_style_shaped(&rounded, shape);
+            let _ = styles::toggle_track_style_shaped(&rounded, v, true, shape);
+            let _ = styles::checkbox_style_shaped(&rounded, v, shape);
+        }
+
+        let json = serde_json::to_string(&Shape::Pill).unwrap();
+        assert_eq!(serde_json::from_str::<Shape>(&json).unwrap(), Shape::Pill);
+    }
+
+    #[test]
+    fn icon_button_targets_grow_with_size() {
+        use floem::style::{Height, Width};
+        use floem::unit::PxPctAuto;
+
+        let palette = Palette::dark();
+        let sizes = [Size::Tiny, Size::Small, Size::Normal, Size::Large, Size::XLarge];
+        for pair in sizes.windows(2) {
+            assert!(pair[0].min_target(&palette) <= pair[1].min_target(&palette));
+        }
+        assert_eq!(Size::Small.min_target(&palette), 24.0);
+        assert_eq!(Size::Normal.min_target(&palette), 32.0);
+        assert_eq!(Size::Large.min_target(&palette), 40.0);
+        assert_eq!(Size::XLarge.min_target(&palette), 48.0);
+
+        // Targets follow UI scale and density like the rest of the layout.
+        let mut scaled = palette;
+        scaled.spacing = scaled.spacing.scaled(2.0);
+        assert_eq!(Size::Normal.min_target(&scaled), 64.0);
+        let mut compact = palette;
+        compact.spacing = compact.spacing.with_density(Density::Compact);
+        // Compact density keeps every size at its absolute minimum.
+        for (size, floor) in [
+            (Size::Tiny, 24.0),
+            (Size::Small, 24.0),
+            (Size::Normal, 32.0),
+            (Size::Large, 40.0),
+            (Size::XLarge, 48.0),
+        ] {
+            assert_eq!(size.min_target(&compact), floor, "{size:?}");
+        }
+
+        // The square side covers the icon plus padding at every size, and
+        // never drops below the size's target.
+        for p in [&palette, &scaled, &compact] {
+            for size in sizes {
+                let t = p.button_tokens(size);
+                let style = styles::icon_button_style(p, Variant::Ghost, size, Fill::Filled);
+                let PxPctAuto::Px(side) = style.get(Width) else {
+                    panic!("{size:?} icon button has no fixed width");
+                };
+                assert_eq!(style.get(Height), PxPctAuto::Px(side));
+                let side = side as f32;
+                assert!(side >= t.font_size + t.pad_v * 2.0, "{size:?} side clips its icon");
+                assert!(side >= size.min_target(p), "{size:?} side below its target");
+            }
+        }
+        assert_eq!(Size::XLarge.radius(&palette), Size::Large.radius(&palette));
     }
 }
@@ -0,0 +1,38 @@
+//! Reduced-motion preference
+
+use std::sync::OnceLock;
+
+use floem_reactive::{RwSignal, Scope, SignalGet, SignalUpdate};
+
+use crate::system::is_system_reduced_motion;
+
+thread_local! {
+    /// Explicit preference; `None` follows the system.
+    static OVERRIDE: RwSignal<Option<bool>> = Scope::new().create_rw_signal(None);
+}
+
+static SYSTEM: OnceLock<bool> = OnceLock::new();
+
+/// Request or refuse reduced motion, overriding the system preference.
+///
+/// While set, every palette's `MotionTokens` durations resolve to zero and
+/// animated palette transitions are skipped. Style closures that read the
+/// palette re-run when this changes.
+pub fn set_reduced_motion(reduced: bool) {
+    OVERRIDE.with(|o| o.set(Some(reduced)));
+}
+
+/// Drop any [`set_reduced_motion`] override and follow the system again.
+pub fn follow_system_reduced_motion() {
+    OVERRIDE.with(|o| o.set(None));
+}
+
+/// Whether reduced motion is in effect (reactive).
+///
+/// The system preference (see `FLEEM_REDUCED_MOTION` and the OS
+/// accessibility settings) is read once and cached.
+pub fn reduced_motion() -> bool {
+    OVERRIDE
+        .with(|o| o.get())
+        .unwrap_or_else(|| *SYSTEM.get_or_init(is_system_reduced_motion))
+}
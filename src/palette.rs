@@ -1,8 +1,14 @@
 //! Palette — bundles all design tokens with built-in dark/light presets
 
 use floem::prelude::Color;
+use floem::text::Weight;
 
-use crate::tokens::{ColorTokens, SpacingTokens, TypographyTokens};
+use crate::tokens::{
+    BadgeTokens, ButtonTokens, CardTokens, ColorSpec, ColorTokens, ComponentTokens,
+    ElevationTokens, InputTokens, ModalTokens, MotionTokens, Shadow, SpacingTokens, TokenError,
+    TypeScale, TypographyTokens,
+};
+use crate::variant::{register_variants, CustomVariant, CustomVariantId, Shape, Size};
 
 /// A complete set of design tokens for one appearance mode.
 #[derive(Debug, Clone, Copy)]
@@ -10,6 +16,14 @@ pub struct Palette {
     pub colors: ColorTokens,
     pub typography: TypographyTokens,
     pub spacing: SpacingTokens,
+    pub motion: MotionTokens,
+    pub elevation: ElevationTokens,
+    /// Variants usable as [`crate::Variant::Custom`], looked up by id.
+    pub custom_variants: &'static [CustomVariant],
+    /// Per-component overrides; see [`ComponentTokens`].
+    pub components: ComponentTokens,
+    /// Corner style for controls.
+    pub shape: Shape,
 }
 
 impl Palette {
@@ -47,21 +61,25 @@ impl Palette {
 
                 // Success
                 success: Color::rgb8(46, 125, 50),
+                success_hover: Color::rgb8(67, 160, 71),
                 success_bg: Color::rgb8(30, 60, 35),
                 success_fg: Color::rgb8(129, 199, 132),
 
                 // Error
                 error: Color::rgb8(211, 47, 47),
+                error_hover: Color::rgb8(229, 57, 53),
                 error_bg: Color::rgb8(60, 30, 30),
                 error_fg: Color::rgb8(239, 154, 154),
 
                 // Warning
                 warning: Color::rgb8(255, 160, 0),
+                warning_hover: Color::rgb8(255, 179, 0),
                 warning_bg: Color::rgb8(60, 50, 30),
                 warning_fg: Color::rgb8(255, 213, 79),
 
                 // Info
                 info: Color::rgb8(33, 150, 243),
+                info_hover: Color::rgb8(66, 165, 245),
                 info_bg: Color::rgb8(30, 45, 60),
                 info_fg: Color::rgb8(144, 202, 249),
 
@@ -77,7 +95,21 @@ impl Palette {
                 font_label: 11.0,
                 font_small: 10.0,
                 font_tiny: 9.0,
+                font_ui: "sans-serif",
+                font_heading_family: "sans-serif",
                 font_mono: "monospace",
+                fallback_cjk: TypographyTokens::CJK_FALLBACK,
+                fallback_arabic: TypographyTokens::ARABIC_FALLBACK,
+                fallback_emoji: TypographyTokens::EMOJI_FALLBACK,
+                weight_title: Weight::BOLD,
+                weight_heading: Weight::SEMIBOLD,
+                weight_body: Weight::NORMAL,
+                weight_caption: Weight::NORMAL,
+                line_height_tight: 1.2,
+                line_height_normal: 1.4,
+                letter_spacing_title: -0.2,
+                letter_spacing_body: 0.0,
+                letter_spacing_caption: 0.2,
             },
             spacing: SpacingTokens {
                 pad_xs: 4.0,
@@ -101,7 +133,44 @@ impl Palette {
                 input_min_width: 150.0,
                 label_width: 90.0,
                 progress_height: 8.0,
+                modal_width: 500.0,
+                hit_target: 32.0,
             },
+            motion: MotionTokens::standard(),
+            elevation: ElevationTokens {
+                level0: Shadow::NONE,
+                level1: Shadow {
+                    blur: 4.0,
+                    offset_x: 0.0,
+                    offset_y: 1.0,
+                    spread: 0.0,
+                    color: Color::rgba8(0, 0, 0, 90),
+                },
+                level2: Shadow {
+                    blur: 8.0,
+                    offset_x: 0.0,
+                    offset_y: 3.0,
+                    spread: 0.0,
+                    color: Color::rgba8(0, 0, 0, 110),
+                },
+                level3: Shadow {
+                    blur: 12.0,
+                    offset_x: 0.0,
+                    offset_y: 4.0,
+                    spread: 0.0,
+                    color: Color::rgba8(0, 0, 0, 130),
+                },
+                level4: Shadow {
+                    blur: 24.0,
+                    offset_x: 0.0,
+                    offset_y: 8.0,
+                    spread: 0.0,
+                    color: Color::rgba8(0, 0, 0, 150),
+                },
+            },
+            custom_variants: &[],
+            components: ComponentTokens::DERIVED,
+            shape: Shape::Rounded,
         }
     }
 
@@ -139,21 +208,25 @@ impl Palette {
 
                 // Success
                 success: Color::rgb8(46, 125, 50),
+                success_hover: Color::rgb8(27, 94, 32),
                 success_bg: Color::rgb8(232, 245, 233),
                 success_fg: Color::rgb8(46, 125, 50),
 
                 // Error
                 error: Color::rgb8(180, 30, 30),
+                error_hover: Color::rgb8(150, 20, 20),
                 error_bg: Color::rgb8(255, 235, 235),
                 error_fg: Color::rgb8(180, 30, 30),
 
                 // Warning
                 warning: Color::rgb8(230, 140, 0),
+                warning_hover: Color::rgb8(200, 120, 0),
                 warning_bg: Color::rgb8(255, 248, 225),
                 warning_fg: Color::rgb8(230, 140, 0),
 
                 // Info
                 info: Color::rgb8(25, 118, 210),
+                info_hover: Color::rgb8(21, 101, 192),
                 info_bg: Color::rgb8(227, 242, 253),
                 info_fg: Color::rgb8(25, 118, 210),
 
@@ -169,7 +242,21 @@ impl Palette {
                 font_label: 11.0,
                 font_small: 10.0,
                 font_tiny: 9.0,
+                font_ui: "sans-serif",
+                font_heading_family: "sans-serif",
                 font_mono: "monospace",
+                fallback_cjk: TypographyTokens::CJK_FALLBACK,
+                fallback_arabic: TypographyTokens::ARABIC_FALLBACK,
+                fallback_emoji: TypographyTokens::EMOJI_FALLBACK,
+                weight_title: Weight::BOLD,
+                weight_heading: Weight::SEMIBOLD,
+                weight_body: Weight::NORMAL,
+                weight_caption: Weight::NORMAL,
+                line_height_tight: 1.2,
+                line_height_normal: 1.4,
+                letter_spacing_title: -0.2,
+                letter_spacing_body: 0.0,
+                letter_spacing_caption: 0.2,
             },
             spacing: SpacingTokens {
                 pad_xs: 4.0,
@@ -193,7 +280,189 @@ impl Palette {
                 input_min_width: 150.0,
                 label_width: 90.0,
                 progress_height: 8.0,
+                modal_width: 500.0,
+                hit_target: 32.0,
+            },
+            motion: MotionTokens::standard(),
+            elevation: ElevationTokens {
+                level0: Shadow::NONE,
+                level1: Shadow {
+                    blur: 4.0,
+                    offset_x: 0.0,
+                    offset_y: 1.0,
+                    spread: 0.0,
+                    color: Color::rgba8(0, 0, 0, 30),
+                },
+                level2: Shadow {
+                    blur: 8.0,
+                    offset_x: 0.0,
+                    offset_y: 3.0,
+                    spread: 0.0,
+                    color: Color::rgba8(0, 0, 0, 40),
+                },
+                level3: Shadow {
+                    blur: 12.0,
+                    offset_x: 0.0,
+                    offset_y: 4.0,
+                    spread: 0.0,
+                    color: Color::rgba8(0, 0, 0, 50),
+                },
+                level4: Shadow {
+                    blur: 24.0,
+                    offset_x: 0.0,
+                    offset_y: 8.0,
+                    spread: 0.0,
+                    color: Color::rgba8(0, 0, 0, 60),
+                },
+            },
+            custom_variants: &[],
+            components: ComponentTokens::DERIVED,
+            shape: Shape::Rounded,
+        }
+    }
+}
+
+impl Palette {
+    /// Interpolate between two palettes.
+    ///
+    /// Colors blend in OKLab, spacing, font sizes, and durations are lerped, and
+    /// non-numeric tokens come from the nearer endpoint. Useful for dimmed
+    /// variants, dusk themes, and theme previews:
+    ///
+    /// ```rust
+    /// use fleem::Palette;
+    ///
+    /// let dusk = Palette::lerp(&Palette::light(), &Palette::dark(), 0.35);
+    /// ```
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            colors: ColorTokens::lerp(&a.colors, &b.colors, t),
+            typography: TypographyTokens::lerp(&a.typography, &b.typography, t),
+            spacing: SpacingTokens::lerp(&a.spacing, &b.spacing, t),
+            motion: MotionTokens::lerp(&a.motion, &b.motion, t),
+            elevation: ElevationTokens::lerp(&a.elevation, &b.elevation, t),
+            custom_variants: if t < 0.5 {
+                a.custom_variants
+            } else {
+                b.custom_variants
             },
+            components: if t < 0.5 { a.components } else { b.components },
+            shape: if t < 0.5 { a.shape } else { b.shape },
+        }
+    }
+
+    /// Regenerate every font size from a modular type scale.
+    ///
+    /// See [`TypeScale`] for which step each size uses.
+    pub fn with_type_scale(mut self, scale: TypeScale) -> Self {
+        self.typography = scale.apply(self.typography);
+        self
+    }
+
+    /// Override color tokens from a spec, resolving `{token}` references.
+    ///
+    /// See [`ColorSpec`] for the value syntax.
+    pub fn with_color_spec(mut self, spec: &ColorSpec) -> Result<Self, TokenError> {
+        self.colors = spec.resolve(&self.colors)?;
+        Ok(self)
+    }
+
+    /// This palette with a different corner style, e.g. in a theme's
+    /// palettes. For a single control use a `*_shaped` recipe instead:
+    ///
+    /// ```rust
+    /// use fleem::{styles, Fill, Palette, Shape, Size, Variant};
+    ///
+    /// let square = Palette::dark().with_shape(Shape::Square);
+    /// let _cta = styles::button_style_shaped(
+    ///     &square,
+    ///     Variant::Primary,
+    ///     Size::Normal,
+    ///     Fill::Filled,
+    ///     Shape::Pill,
+    /// );
+    /// ```
+    pub fn with_shape(mut self, shape: Shape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Register the variants usable as [`crate::Variant::Custom`].
+    ///
+    /// The list is kept for the rest of the program, shared with every
+    /// palette that registers an equal one.
+    pub fn with_custom_variants(
+        mut self,
+        variants: impl IntoIterator<Item = CustomVariant>,
+    ) -> Self {
+        self.custom_variants = register_variants(variants.into_iter().collect());
+        self
+    }
+
+    /// A registered custom variant, if any.
+    pub fn custom_variant(&self, id: CustomVariantId) -> Option<&CustomVariant> {
+        self.custom_variants.iter().find(|v| v.id == id)
+    }
+
+    /// Button dimensions for `size`: derived, with the theme's patch and
+    /// `shape` applied.
+    pub fn button_tokens(&self, size: Size) -> ButtonTokens {
+        self.button_tokens_shaped(size, self.shape)
+    }
+
+    pub(crate) fn button_tokens_shaped(&self, size: Size, shape: Shape) -> ButtonTokens {
+        let tokens = ButtonTokens::derive(self, size);
+        let tokens = self.components.button.map_or(tokens, |p| p.apply(tokens));
+        ButtonTokens {
+            radius: shape.radius(tokens.radius),
+            ..tokens
+        }
+    }
+
+    /// Text input dimensions: derived, with the theme's patch and `shape`
+    /// applied.
+    pub fn input_tokens(&self) -> InputTokens {
+        self.input_tokens_shaped(self.shape)
+    }
+
+    pub(crate) fn input_tokens_shaped(&self, shape: Shape) -> InputTokens {
+        let tokens = InputTokens::derive(self);
+        let tokens = self.components.input.map_or(tokens, |p| p.apply(tokens));
+        InputTokens {
+            radius: shape.radius(tokens.radius),
+            ..tokens
+        }
+    }
+
+    /// Card dimensions: derived, with the theme's patch applied.
+    pub fn card_tokens(&self) -> CardTokens {
+        let tokens = CardTokens::derive(self);
+        self.components.card.map_or(tokens, |p| p.apply(tokens))
+    }
+
+    /// Badge dimensions: derived, with the theme's patch and `shape`
+    /// applied.
+    pub fn badge_tokens(&self) -> BadgeTokens {
+        self.badge_tokens_shaped(self.shape)
+    }
+
+    pub(crate) fn badge_tokens_shaped(&self, shape: Shape) -> BadgeTokens {
+        let tokens = BadgeTokens::derive(self);
+        let tokens = self.components.badge.map_or(tokens, |p| p.apply(tokens));
+        BadgeTokens {
+            radius: shape.radius(tokens.radius),
+            ..tokens
         }
     }
+
+    /// Modal card dimensions: derived, with the theme's patch applied.
+    pub fn modal_tokens(&self) -> ModalTokens {
+        let tokens = ModalTokens::derive(self);
+        self.components.modal.map_or(tokens, |p| p.apply(tokens))
+    }
+
+    /// Shadow for an elevation level (0 = flat), clamped to the highest level.
+    pub fn elevation(&self, level: u8) -> Shadow {
+        self.elevation.get(level)
+    }
 }
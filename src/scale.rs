@@ -0,0 +1,88 @@
+//! App-wide UI scale factor
+
+use floem_reactive::{RwSignal, Scope, SignalGet, SignalUpdate};
+use serde::{Deserialize, Serialize};
+
+/// Multiplier for font sizes, spacing, dimensions, radii, and shadows.
+///
+/// Values are clamped to [`UiScale::MIN`]`..=`[`UiScale::MAX`], including
+/// when deserialized, so a corrupted settings file can't make the UI
+/// unusable. Serializes as a bare number.
+///
+/// ```rust
+/// use fleem::UiScale;
+///
+/// assert_eq!(UiScale::new(1.25).factor(), 1.25);
+/// assert_eq!(UiScale::new(10.0), UiScale::new(UiScale::MAX));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(from = "f32", into = "f32")]
+pub struct UiScale(f32);
+
+impl UiScale {
+    /// Smallest allowed scale.
+    pub const MIN: f32 = 0.5;
+    /// Largest allowed scale.
+    pub const MAX: f32 = 3.0;
+    /// No scaling.
+    pub const NORMAL: UiScale = UiScale(1.0);
+
+    /// Create a scale, clamped to the allowed range. NaN becomes `1.0`.
+    pub fn new(factor: f32) -> Self {
+        if factor.is_nan() {
+            return Self::NORMAL;
+        }
+        Self(factor.clamp(Self::MIN, Self::MAX))
+    }
+
+    /// The multiplier.
+    pub fn factor(self) -> f32 {
+        self.0
+    }
+}
+
+impl Default for UiScale {
+    fn default() -> Self {
+        Self::NORMAL
+    }
+}
+
+impl From<f32> for UiScale {
+    fn from(factor: f32) -> Self {
+        Self::new(factor)
+    }
+}
+
+impl From<UiScale> for f32 {
+    fn from(scale: UiScale) -> Self {
+        scale.0
+    }
+}
+
+thread_local! {
+    /// Owned by its own root scope so it outlives whichever scope happens to
+    /// touch it first.
+    static UI_SCALE: RwSignal<UiScale> = Scope::new().create_rw_signal(UiScale::NORMAL);
+}
+
+/// Scale every resolved palette's typography, spacing, and elevation tokens.
+///
+/// Applies to the global theme and every scope; the value is clamped by
+/// [`UiScale::new`].
+///
+/// # Panics
+///
+/// Panics if called outside a reactive runtime.
+pub fn set_ui_scale(scale: impl Into<UiScale>) {
+    let scale = scale.into();
+    UI_SCALE.with(|s| s.set(scale));
+}
+
+/// The app-wide UI scale (reactive).
+///
+/// # Panics
+///
+/// Panics if called outside a reactive runtime.
+pub fn ui_scale() -> UiScale {
+    UI_SCALE.with(|s| s.get())
+}
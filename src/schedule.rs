@@ -0,0 +1,121 @@
+//! Time-of-day scheduling for `Appearance::Scheduled`
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::Duration;
+
+use floem::action::exec_after;
+use floem_reactive::{RwSignal, Scope, SignalGet, SignalUpdate};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::system::local_time;
+
+/// How often scheduled appearances re-check the clock.
+const RECHECK: Duration = Duration::from_secs(30);
+
+/// A local wall-clock time with minute precision.
+///
+/// Deserializing rejects hours above 23 and minutes above 59.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct TimeOfDay {
+    pub hour: u8,
+    pub minute: u8,
+}
+
+impl TimeOfDay {
+    /// Create a time of day. Out-of-range values wrap into `00:00..=23:59`.
+    pub const fn new(hour: u8, minute: u8) -> Self {
+        Self {
+            hour: ((hour as u16 + minute as u16 / 60) % 24) as u8,
+            minute: minute % 60,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeOfDay {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Raw {
+            hour: u8,
+            minute: u8,
+        }
+
+        let Raw { hour, minute } = Raw::deserialize(deserializer)?;
+        if hour > 23 || minute > 59 {
+            return Err(D::Error::custom(format!(
+                "time {hour:02}:{minute:02} is outside 00:00..=23:59"
+            )));
+        }
+        Ok(TimeOfDay::new(hour, minute))
+    }
+}
+
+/// Source of the current local time for scheduled appearances.
+///
+/// Install one with [`set_clock`] to drive schedules from a fixed or
+/// simulated time, e.g. in tests. Closures returning `TimeOfDay` implement it.
+pub trait Clock {
+    /// The current local time of day.
+    fn now(&self) -> TimeOfDay;
+}
+
+impl<F: Fn() -> TimeOfDay> Clock for F {
+    fn now(&self) -> TimeOfDay {
+        self()
+    }
+}
+
+thread_local! {
+    static CLOCK: RefCell<Option<Rc<dyn Clock>>> = const { RefCell::new(None) };
+    static TICKING: Cell<bool> = const { Cell::new(false) };
+    /// Bumped every `RECHECK`; read by scheduled resolution so style closures
+    /// re-run and pick up a boundary crossing. Owned by its own root scope so
+    /// it outlives whichever scope happens to touch it first.
+    static SCHEDULE_TICK: RwSignal<u64> = Scope::new().create_rw_signal(0);
+}
+
+/// Replace the clock used by `Appearance::Scheduled`. `None` restores the
+/// system clock.
+pub fn set_clock(clock: Option<Rc<dyn Clock>>) {
+    CLOCK.with(|c| *c.borrow_mut() = clock);
+}
+
+/// The current time of day from the installed clock.
+pub fn time_of_day() -> TimeOfDay {
+    if let Some(clock) = CLOCK.with(|c| c.borrow().clone()) {
+        return clock.now();
+    }
+    let (hour, minute) = local_time();
+    TimeOfDay::new(hour, minute)
+}
+
+/// Whether a `light_from`/`dark_from` schedule is in its light period at `now`.
+///
+/// The light period starts at `light_from` and ends at `dark_from`, wrapping
+/// past midnight if `dark_from` is earlier. Equal times mean always light.
+pub(crate) fn is_light_at(light_from: TimeOfDay, dark_from: TimeOfDay, now: TimeOfDay) -> bool {
+    if light_from <= dark_from {
+        light_from == dark_from || (light_from <= now && now < dark_from)
+    } else {
+        now >= light_from || now < dark_from
+    }
+}
+
+/// Subscribe the caller to periodic re-checks of the clock.
+///
+/// Starts a floem timer on first use so reactive closures re-run as
+/// scheduled boundaries pass.
+pub(crate) fn track_schedule() {
+    SCHEDULE_TICK.with(|tick| tick.get());
+    if TICKING.with(|t| t.replace(true)) {
+        return;
+    }
+    fn tick() {
+        exec_after(RECHECK, |_| {
+            SCHEDULE_TICK.with(|tick| tick.update(|n| *n += 1));
+            tick();
+        });
+    }
+    tick();
+}
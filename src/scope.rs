@@ -0,0 +1,225 @@
+//! Scoped theme overrides for a view subtree
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use floem_reactive::{with_scope, RwSignal, Scope, SignalGet, SignalUpdate};
+
+use crate::density::{density, Density};
+use crate::fonts;
+use crate::palette::Palette;
+use crate::theme::{
+    global_appearance, global_palette, global_theme_id, next_theme_id, theme_palette, Appearance,
+    ResolvedAppearance, ThemeDef,
+};
+use crate::transition;
+
+thread_local! {
+    /// Source of scope ids. Id 0 is reserved for the global theme.
+    static NEXT_SCOPE_ID: Cell<u64> = const { Cell::new(1) };
+    /// Scopes entered by [`theme_scope`] and by re-running themed style
+    /// closures, innermost last. `None` entries mask outer scopes.
+    static SCOPE_STACK: RefCell<Vec<Option<ThemeScope>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A theme and/or appearance override for a view subtree.
+///
+/// Unset fields inherit from the enclosing scope, and ultimately from the
+/// global state set by `init()`. Scopes are cheap `Copy` handles backed by
+/// signals, so overrides can be changed after the subtree is built.
+///
+/// ```rust,no_run
+/// use fleem::{theme_scope, Appearance, ThemeScope, ThemedExt};
+/// use floem::views::label;
+///
+/// // A dark sidebar inside a light window.
+/// let sidebar = theme_scope(ThemeScope::new().appearance(Appearance::Dark), || {
+///     label(|| "Sidebar").themed(|p, s| s.background(p.colors.bg_surface))
+/// });
+/// ```
+#[derive(Clone, Copy)]
+pub struct ThemeScope {
+    id: u64,
+    parent: Option<RwSignal<ThemeScope>>,
+    theme: RwSignal<Option<(u64, Rc<dyn ThemeDef>)>>,
+    appearance: RwSignal<Option<Appearance>>,
+    density: RwSignal<Option<Density>>,
+}
+
+impl ThemeScope {
+    /// Create a scope nested in the current one that overrides nothing yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside a reactive runtime.
+    pub fn new() -> Self {
+        Self::with_parent(current_scope())
+    }
+
+    /// Create a scope that inherits straight from the global state,
+    /// regardless of the scope currently being built.
+    pub(crate) fn root() -> Self {
+        Self::with_parent(None)
+    }
+
+    fn with_parent(parent: Option<ThemeScope>) -> Self {
+        Self {
+            id: NEXT_SCOPE_ID.with(|id| id.replace(id.get() + 1)),
+            parent: parent.map(RwSignal::new),
+            theme: RwSignal::new(None),
+            appearance: RwSignal::new(None),
+            density: RwSignal::new(None),
+        }
+    }
+
+    /// Override the theme for this scope.
+    pub fn theme(self, theme: impl ThemeDef) -> Self {
+        self.set_theme(Some(Rc::new(theme)));
+        self
+    }
+
+    /// Override the appearance for this scope.
+    pub fn appearance(self, appearance: Appearance) -> Self {
+        self.set_appearance(Some(appearance));
+        self
+    }
+
+    /// Override the layout density for this scope.
+    pub fn density(self, density: Density) -> Self {
+        self.set_density(Some(density));
+        self
+    }
+
+    /// Replace the theme override. `None` inherits from the enclosing scope.
+    ///
+    /// Fonts bundled by the theme are registered with floem's text system.
+    pub fn set_theme(&self, theme: Option<Rc<dyn ThemeDef>>) {
+        if let Some(theme) = &theme {
+            fonts::register(theme.as_ref());
+        }
+        self.theme.set(theme.map(|t| (next_theme_id(), t)));
+    }
+
+    /// Replace the appearance override. `None` inherits from the enclosing scope.
+    pub fn set_appearance(&self, appearance: Option<Appearance>) {
+        self.appearance.set(appearance);
+    }
+
+    /// Replace the density override. `None` inherits from the enclosing scope.
+    pub fn set_density(&self, density: Option<Density>) {
+        self.density.set(density);
+    }
+
+    /// The effective appearance preference for this scope (reactive).
+    pub fn effective_appearance(&self) -> Appearance {
+        match self.appearance.get() {
+            Some(appearance) => appearance,
+            None => match self.parent {
+                Some(parent) => parent.get_untracked().effective_appearance(),
+                None => global_appearance(),
+            },
+        }
+    }
+
+    /// The effective layout density for this scope (reactive).
+    pub fn effective_density(&self) -> Density {
+        match self.density.get() {
+            Some(density) => density,
+            None => match self.parent {
+                Some(parent) => parent.get_untracked().effective_density(),
+                None => density(),
+            },
+        }
+    }
+
+    /// The effective `Palette` for this scope (reactive).
+    ///
+    /// Mid-transition this is the cross-faded palette; see
+    /// [`set_palette_transition`](crate::set_palette_transition).
+    pub fn palette(&self) -> Palette {
+        let resolved = self.effective_appearance().resolve();
+        let density = self.effective_density();
+        let (key, target) = match self.effective_theme() {
+            Some((id, theme)) => (
+                (id, resolved),
+                theme_palette(theme.as_ref(), resolved, density),
+            ),
+            None => (
+                (global_theme_id(), resolved),
+                global_palette(resolved, density),
+            ),
+        };
+        transition::apply(self.id, key, target)
+    }
+
+    /// Theme id and resolved appearance, used as a cache key.
+    pub(crate) fn key(&self) -> (u64, ResolvedAppearance) {
+        let id = self
+            .effective_theme()
+            .map_or_else(global_theme_id, |(id, _)| id);
+        (id, self.effective_appearance().resolve())
+    }
+
+    fn effective_theme(&self) -> Option<(u64, Rc<dyn ThemeDef>)> {
+        match self.theme.get() {
+            Some(theme) => Some(theme),
+            None => self
+                .parent
+                .and_then(|p| p.get_untracked().effective_theme()),
+        }
+    }
+}
+
+impl Default for ThemeScope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The nearest scope enclosing the caller, if any.
+///
+/// Set while [`theme_scope`] builds its subtree and while a
+/// [`ThemedExt`](crate::ThemedExt) style closure runs, so it is `None` in a
+/// plain `.style()` closure that re-runs after its subtree was built.
+/// Capture it while building to resolve such closures later:
+///
+/// ```rust,no_run
+/// use fleem::{current_scope, palette, ThemeScope};
+/// use floem::views::{label, Decorators};
+///
+/// let view = label(|| "Plain style").style({
+///     let scope = current_scope();
+///     move |s| {
+///         let p = scope.map_or_else(palette, |scope| scope.palette());
+///         s.color(p.colors.text_primary)
+///     }
+/// });
+/// ```
+pub fn current_scope() -> Option<ThemeScope> {
+    SCOPE_STACK.with(|stack| stack.borrow().last().copied().flatten())
+}
+
+/// Run `f` with `scope` as the nearest scope, or with none when `None`.
+pub(crate) fn in_scope<T>(scope: Option<ThemeScope>, f: impl FnOnce() -> T) -> T {
+    struct Pop;
+    impl Drop for Pop {
+        fn drop(&mut self) {
+            SCOPE_STACK.with(|stack| stack.borrow_mut().pop());
+        }
+    }
+
+    SCOPE_STACK.with(|stack| stack.borrow_mut().push(scope));
+    let _pop = Pop;
+    f()
+}
+
+/// Build a view subtree with `scope` as its nearest theme scope.
+///
+/// `palette()` and the cached recipes resolve against `scope` while `child`
+/// runs. `ThemedExt` methods capture it, so their style closures keep
+/// resolving against `scope` when they re-run later. Signals and effects
+/// created in `child` are owned by a child of the current reactive scope.
+pub fn theme_scope<V: 'static>(scope: ThemeScope, child: impl FnOnce() -> V) -> V {
+    let cx = Scope::current().create_child();
+    with_scope(cx, || in_scope(Some(scope), child))
+}
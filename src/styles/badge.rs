@@ -2,15 +2,21 @@
 
 use floem::style::Style;
 
+use crate::blend::readable_on;
 use crate::palette::Palette;
-use crate::variant::Variant;
+use crate::variant::{Fill, Shape, Variant};
 
 /// Style recipe for variant-colored badges.
 ///
-/// Compact inline element with tinted background and matching text.
+/// Compact inline element with tinted background and matching text:
+/// semantic variants pair their `*_bg` and `*_fg` tokens, Primary is solid
+/// accent, and everything else sits on `bg_elevated`. Text that would fall
+/// below 4.5:1 contrast on its background is darkened or lightened until it
+/// reaches it. Dimensions come from [`Palette::badge_tokens`]; use
+/// [`badge_fill_style`] for [`Fill`] colors.
 pub fn badge_style(palette: &Palette, variant: Variant) -> Style {
     let c = &palette.colors;
-    let sp = &palette.spacing;
+    let t = palette.badge_tokens();
 
     let (bg, fg) = match variant {
         Variant::Primary => (c.accent, c.accent_fg),
@@ -22,12 +28,45 @@ pub fn badge_style(palette: &Palette, variant: Variant) -> Style {
     };
 
     Style::new()
-        .padding_horiz(sp.pad_lg)
-        .padding_vert(sp.pad_sm)
+        .padding_horiz(t.pad_h)
+        .padding_vert(t.pad_v)
         .background(bg)
-        .color(fg)
-        .border_radius(sp.radius_md)
-        .font_size(palette.typography.font_body)
+        .color(readable_on(fg, bg, 4.5))
+        .border_radius(t.radius)
+        .font_size(t.font_size)
+}
+
+/// Style recipe for badges with an explicit [`Fill`].
+///
+/// Compact inline element colored from [`Variant::resolve`]:
+/// `Fill::Soft` is the tinted look, `Fill::Filled` a solid one, and
+/// `Fill::Outlined` / `Fill::Dashed` a bordered one. Badges aren't
+/// interactive, so hover colors are not applied. Dimensions come from
+/// [`Palette::badge_tokens`].
+pub fn badge_fill_style(palette: &Palette, variant: Variant, fill: Fill) -> Style {
+    badge_fill_style_shaped(palette, variant, fill, palette.shape)
+}
+
+/// [`badge_fill_style`] with corners from `shape` instead of
+/// `palette.shape`.
+pub fn badge_fill_style_shaped(
+    palette: &Palette,
+    variant: Variant,
+    fill: Fill,
+    shape: Shape,
+) -> Style {
+    let vc = variant.resolve(fill, palette);
+    let t = palette.badge_tokens_shaped(shape);
+
+    Style::new()
+        .padding_horiz(t.pad_h)
+        .padding_vert(t.pad_v)
+        .background(vc.bg)
+        .color(vc.fg)
+        .border(fill.stroke(palette.spacing.border_width))
+        .border_color(vc.border)
+        .border_radius(t.radius)
+        .font_size(t.font_size)
 }
 
 /// Style recipe for a boolean status badge (success or error).
@@ -1,32 +1,51 @@
 //! Button style recipe
 
-use floem::style::Style;
+use floem::style::{Background, Style, TextColor};
 
 use crate::palette::Palette;
-use crate::variant::{Fill, Size, Variant};
+use crate::variant::{Fill, Shape, Size, Variant};
 
 /// Style recipe for buttons.
 ///
 /// Applies background, foreground, border, padding, radius, and
-/// hover/disabled/focus states based on the variant, size, and fill.
+/// hover/active/disabled/focus states based on the variant, size, and fill.
+/// Dimensions come from [`Palette::button_tokens`]. Color changes ease over
+/// `palette.motion`'s fast duration.
 pub fn button_style(palette: &Palette, variant: Variant, size: Size, fill: Fill) -> Style {
+    button_style_shaped(palette, variant, size, fill, palette.shape)
+}
+
+/// [`button_style`] with corners from `shape` instead of `palette.shape`.
+pub fn button_style_shaped(
+    palette: &Palette,
+    variant: Variant,
+    size: Size,
+    fill: Fill,
+    shape: Shape,
+) -> Style {
     let vc = variant.resolve(fill, palette);
-    let (pad_v, pad_h) = size.padding(palette);
-    let radius = size.radius(palette);
-    let font = size.font_size(palette);
+    let t = palette.button_tokens_shaped(size, shape);
     let c = &palette.colors;
 
     Style::new()
-        .padding_vert(pad_v)
-        .padding_horiz(pad_h)
+        .padding_vert(t.pad_v)
+        .padding_horiz(t.pad_h)
         .background(vc.bg)
         .color(vc.fg)
-        .border(palette.spacing.border_width)
+        .border(fill.stroke(t.border_width))
         .border_color(vc.border)
-        .border_radius(radius)
-        .font_size(font)
+        .border_radius(t.radius)
+        .font_size(t.font_size)
         .cursor(floem::style::CursorStyle::Pointer)
-        .hover(|s| s.background(vc.bg_hover))
+        .apply_opt(palette.motion.fast(), |s, t| {
+            s.transition(Background, t.clone()).transition(TextColor, t)
+        })
+        .hover(|s| {
+            s.background(vc.bg_hover)
+                .color(vc.fg_hover)
+                .border_color(vc.border_hover)
+        })
+        .active(|s| s.background(vc.bg_active))
         .disabled(|s| {
             s.background(c.bg_disabled)
                 .color(c.text_disabled)
@@ -35,6 +54,37 @@ pub fn button_style(palette: &Palette, variant: Variant, size: Size, fill: Fill)
         })
         .focus(|s| {
             s.border_color(c.border_focus)
-                .border(palette.spacing.border_width_thick)
+                .border(fill.stroke(t.border_width_focus))
         })
 }
+
+/// Style recipe for icon-only buttons.
+///
+/// Colors and states match [`button_style`], but the button is a centered
+/// square: its side is the icon's font size plus vertical padding on both
+/// sides, and never smaller than [`Size::min_target`].
+pub fn icon_button_style(palette: &Palette, variant: Variant, size: Size, fill: Fill) -> Style {
+    icon_button_style_shaped(palette, variant, size, fill, palette.shape)
+}
+
+/// [`icon_button_style`] with corners from `shape` instead of
+/// `palette.shape`; `Shape::Pill` makes a round button.
+pub fn icon_button_style_shaped(
+    palette: &Palette,
+    variant: Variant,
+    size: Size,
+    fill: Fill,
+    shape: Shape,
+) -> Style {
+    let t = palette.button_tokens(size);
+    let side = (t.font_size + t.pad_v * 2.0).max(size.min_target(palette));
+
+    button_style_shaped(palette, variant, size, fill, shape)
+        .padding(0.0)
+        .width(side)
+        .height(side)
+        .min_width(side)
+        .min_height(side)
+        .items_center()
+        .justify_center()
+}
@@ -3,19 +3,22 @@
 use floem::style::Style;
 
 use crate::palette::Palette;
+use crate::styles::elevation_style;
 
 /// Style recipe for card/panel containers.
 ///
-/// Full width, surface background, border, rounded corners.
+/// Full width, surface background, border, rounded corners, and a shadow
+/// (level 1 by default). Dimensions come from [`Palette::card_tokens`].
 pub fn card_style(palette: &Palette) -> Style {
     let c = &palette.colors;
-    let sp = &palette.spacing;
+    let t = palette.card_tokens();
 
     Style::new()
         .width_full()
-        .padding(sp.pad_xl)
+        .padding(t.padding)
         .background(c.bg_surface)
-        .border(sp.border_width)
+        .border(t.border_width)
         .border_color(c.border)
-        .border_radius(sp.radius_xl)
+        .border_radius(t.radius)
+        .apply(elevation_style(palette, t.elevation))
 }
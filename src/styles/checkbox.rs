@@ -1,28 +1,32 @@
 //! Checkbox style recipe
 
 use floem::style::Style;
+use floem::views::CheckboxClass;
 
 use crate::palette::Palette;
-use crate::variant::Variant;
+use crate::variant::{Shape, Variant};
 
 /// Style recipe for checkbox containers (the row wrapping checkbox + label).
 ///
-/// Provides consistent alignment and cursor. The variant controls the
-/// accent color used for the checked state.
+/// Provides consistent alignment and cursor, and rounds the box per
+/// `palette.shape`. The variant controls the accent color used for the
+/// checked state.
 pub fn checkbox_style(palette: &Palette, variant: Variant) -> Style {
+    checkbox_style_shaped(palette, variant, palette.shape)
+}
+
+/// [`checkbox_style`] with the box's corners from `shape` instead of
+/// `palette.shape`.
+pub fn checkbox_style_shaped(palette: &Palette, variant: Variant, shape: Shape) -> Style {
     let c = &palette.colors;
     let sp = &palette.spacing;
 
-    let accent = match variant {
-        Variant::Primary => c.accent,
-        Variant::Success => c.success,
-        Variant::Warning => c.warning,
-        Variant::Error => c.error,
-        Variant::Info => c.info,
-        _ => c.accent,
-    };
+    let accent = variant.accent(palette);
 
     Style::new()
+        .class(CheckboxClass, |s| {
+            s.border_radius(shape.radius(sp.radius_sm))
+        })
         .items_center()
         .gap(sp.pad_sm)
         .font_size(palette.typography.font_body)
@@ -0,0 +1,49 @@
+//! Chip style recipe
+
+use floem::style::{Background, Style, TextColor};
+
+use crate::palette::Palette;
+use crate::variant::{Fill, Shape, Variant};
+
+/// Style recipe for chips: compact, fully rounded tags and filters.
+///
+/// Chips stay pill-shaped unless the palette's shape is `Shape::Square`.
+///
+/// Colors come from [`Variant::resolve`] for `fill`, with hover and pressed
+/// states. A selected chip (floem's `selected` state) switches to the
+/// `Fill::Filled` colors, so filter chips read as on/off.
+pub fn chip_style(palette: &Palette, variant: Variant, fill: Fill) -> Style {
+    let vc = variant.resolve(fill, palette);
+    let on = variant.resolve(Fill::Filled, palette);
+    let c = &palette.colors;
+    let sp = &palette.spacing;
+
+    Style::new()
+        .items_center()
+        .gap(sp.pad_xs)
+        .padding_vert(sp.pad_xs)
+        .padding_horiz(sp.pad_md)
+        .background(vc.bg)
+        .color(vc.fg)
+        .border(fill.stroke(sp.border_width))
+        .border_color(vc.border)
+        .border_radius(palette.shape.radius(Shape::PILL_RADIUS))
+        .font_size(palette.typography.font_label)
+        .cursor(floem::style::CursorStyle::Pointer)
+        .apply_opt(palette.motion.fast(), |s, t| {
+            s.transition(Background, t.clone()).transition(TextColor, t)
+        })
+        .hover(|s| {
+            s.background(vc.bg_hover)
+                .color(vc.fg_hover)
+                .border_color(vc.border_hover)
+        })
+        .active(|s| s.background(vc.bg_active))
+        .selected(|s| s.background(on.bg).color(on.fg).border_color(on.border))
+        .disabled(|s| {
+            s.background(c.bg_disabled)
+                .color(c.text_disabled)
+                .border_color(c.border)
+                .cursor(floem::style::CursorStyle::Default)
+        })
+}
@@ -0,0 +1,22 @@
+//! Elevation style recipe
+
+use floem::style::Style;
+
+use crate::palette::Palette;
+
+/// Style recipe that applies the box shadow for an elevation level.
+///
+/// Level 0 (and any level whose shadow draws nothing) adds no shadow.
+pub fn elevation_style(palette: &Palette, level: u8) -> Style {
+    let shadow = palette.elevation(level);
+    if !shadow.is_visible() {
+        return Style::new();
+    }
+
+    Style::new()
+        .box_shadow_blur(shadow.blur)
+        .box_shadow_h_offset(shadow.offset_x)
+        .box_shadow_v_offset(shadow.offset_y)
+        .box_shadow_spread(shadow.spread)
+        .box_shadow_color(shadow.color)
+}
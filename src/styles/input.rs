@@ -3,24 +3,31 @@
 use floem::style::Style;
 
 use crate::palette::Palette;
+use crate::variant::Shape;
 
 /// Style recipe for text inputs.
 ///
 /// Applies background, border, padding, min-width, and focus/disabled states.
+/// Dimensions come from [`Palette::input_tokens`].
 pub fn input_style(palette: &Palette) -> Style {
+    input_style_shaped(palette, palette.shape)
+}
+
+/// [`input_style`] with corners from `shape` instead of `palette.shape`.
+pub fn input_style_shaped(palette: &Palette, shape: Shape) -> Style {
     let c = &palette.colors;
-    let sp = &palette.spacing;
+    let t = palette.input_tokens_shaped(shape);
 
     Style::new()
-        .padding_vert(sp.pad_sm)
-        .padding_horiz(sp.pad_md)
+        .padding_vert(t.pad_v)
+        .padding_horiz(t.pad_h)
         .background(c.bg_input)
         .color(c.text_primary)
-        .border(sp.border_width)
+        .border(t.border_width)
         .border_color(c.border)
-        .border_radius(sp.radius_md)
-        .font_size(palette.typography.font_body)
-        .min_width(sp.input_min_width)
+        .border_radius(t.radius)
+        .font_size(t.font_size)
+        .min_width(t.min_width)
         .focus(|s| s.border_color(c.border_focus))
         .disabled(|s| {
             s.background(c.bg_disabled)
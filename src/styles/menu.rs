@@ -0,0 +1,59 @@
+//! Menu and tooltip style recipes
+
+use floem::style::Style;
+
+use crate::palette::Palette;
+use crate::styles::elevation_style;
+
+/// Style recipe for dropdown and context menu containers.
+///
+/// Elevated background, border, and level 2 shadow.
+pub fn menu_style(palette: &Palette) -> Style {
+    let c = &palette.colors;
+    let sp = &palette.spacing;
+
+    Style::new()
+        .flex_col()
+        .padding(sp.pad_xs)
+        .background(c.bg_elevated)
+        .color(c.text_primary)
+        .border(sp.border_width)
+        .border_color(c.border)
+        .border_radius(sp.radius_lg)
+        .font_size(palette.typography.font_body)
+        .apply(elevation_style(palette, 2))
+}
+
+/// Style recipe for a single menu entry.
+pub fn menu_item_style(palette: &Palette) -> Style {
+    let c = &palette.colors;
+    let sp = &palette.spacing;
+
+    Style::new()
+        .padding_vert(sp.pad_xs)
+        .padding_horiz(sp.pad_md)
+        .border_radius(sp.radius_sm)
+        .cursor(floem::style::CursorStyle::Pointer)
+        .hover(|s| s.background(c.bg_hover))
+        .disabled(|s| {
+            s.color(c.text_disabled)
+                .cursor(floem::style::CursorStyle::Default)
+        })
+}
+
+/// Style recipe for tooltips and popovers.
+///
+/// Compact inverse-contrast box with a level 3 shadow.
+pub fn tooltip_style(palette: &Palette) -> Style {
+    let c = &palette.colors;
+    let sp = &palette.spacing;
+
+    Style::new()
+        .padding_vert(sp.pad_xs)
+        .padding_horiz(sp.pad_md)
+        .background(c.text_primary)
+        .color(c.text_inverse)
+        .border_radius(sp.radius_md)
+        .font_size(palette.typography.font_small)
+        .apply(elevation_style(palette, 3))
+}
@@ -7,18 +7,31 @@ mod badge;
 mod button;
 mod card;
 mod checkbox;
+mod chip;
 mod drop_zone;
+mod elevation;
 mod input;
+mod menu;
 mod overlay;
 mod progress;
+mod text;
 mod toggle;
 
-pub use badge::{badge_style, status_badge_style};
-pub use button::button_style;
+pub use badge::{badge_fill_style, badge_fill_style_shaped, badge_style, status_badge_style};
+pub use button::{button_style, button_style_shaped, icon_button_style, icon_button_style_shaped};
 pub use card::card_style;
-pub use checkbox::checkbox_style;
+pub use checkbox::{checkbox_style, checkbox_style_shaped};
+pub use chip::chip_style;
 pub use drop_zone::drop_zone_style;
-pub use input::input_style;
-pub use overlay::{modal_backdrop_style, modal_card_style};
+pub use elevation::elevation_style;
+pub use input::{input_style, input_style_shaped};
+pub use menu::{menu_item_style, menu_style, tooltip_style};
+pub use overlay::{
+    modal_backdrop_style, modal_backdrop_style_at, modal_card_style, MODAL_BASE_Z_INDEX,
+    MODAL_Z_INDEX_STEP,
+};
 pub use progress::{progress_fill_style, progress_track_style};
-pub use toggle::toggle_style;
+pub use text::{
+    body_text_style, caption_text_style, code_text_style, heading_text_style, title_text_style,
+};
+pub use toggle::{toggle_style, toggle_track_style, toggle_track_style_shaped};
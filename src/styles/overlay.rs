@@ -3,13 +3,29 @@
 use floem::style::{Display, Position, Style};
 
 use crate::palette::Palette;
+use crate::styles::elevation_style;
+
+/// z-index of the bottom-most modal backdrop.
+pub const MODAL_BASE_Z_INDEX: i32 = 100;
+
+/// z-index step between stacked modals.
+pub const MODAL_Z_INDEX_STEP: i32 = 10;
 
 /// Style recipe for a modal backdrop.
 ///
 /// Covers the entire parent (absolute positioned), semi-transparent background.
 /// When `visible` is false, the element is hidden via `display: none`.
 pub fn modal_backdrop_style(palette: &Palette, visible: bool) -> Style {
+    modal_backdrop_style_at(palette, visible, 0)
+}
+
+/// Style recipe for a modal backdrop at a given stacking depth.
+///
+/// Depth 0 is the bottom-most modal; each level above it gets a z-index
+/// `MODAL_Z_INDEX_STEP` higher so nested modals cover their parents.
+pub fn modal_backdrop_style_at(palette: &Palette, visible: bool, depth: u32) -> Style {
     let c = &palette.colors;
+    let z_index = MODAL_BASE_Z_INDEX + depth as i32 * MODAL_Z_INDEX_STEP;
 
     if visible {
         Style::new()
@@ -21,22 +37,26 @@ pub fn modal_backdrop_style(palette: &Palette, visible: bool) -> Style {
             .items_center()
             .justify_center()
             .background(c.bg_overlay)
-            .z_index(100)
+            .z_index(z_index)
     } else {
         Style::new().display(Display::None)
     }
 }
 
 /// Style recipe for a modal card (the content box inside the backdrop).
+///
+/// Uses the level 4 shadow by default. Dimensions come from
+/// [`Palette::modal_tokens`].
 pub fn modal_card_style(palette: &Palette) -> Style {
     let c = &palette.colors;
-    let sp = &palette.spacing;
+    let t = palette.modal_tokens();
 
     Style::new()
-        .padding(sp.pad_xl * 1.5)
+        .padding(t.padding)
         .background(c.bg_surface)
-        .border(sp.border_width)
+        .border(t.border_width)
         .border_color(c.border_strong)
-        .border_radius(sp.radius_xl)
-        .width(500.0)
+        .border_radius(t.radius)
+        .width(t.width)
+        .apply(elevation_style(palette, t.elevation))
 }
@@ -1,6 +1,6 @@
 //! Progress bar style recipes
 
-use floem::style::Style;
+use floem::style::{Style, Width};
 
 use crate::palette::Palette;
 
@@ -19,6 +19,7 @@ pub fn progress_track_style(palette: &Palette) -> Style {
 /// Style recipe for the progress bar fill.
 ///
 /// `pct` is the progress percentage (0.0–100.0), used to set width.
+/// Width changes ease over `palette.motion`'s normal duration.
 pub fn progress_fill_style(palette: &Palette, pct: f64) -> Style {
     let c = &palette.colors;
     let sp = &palette.spacing;
@@ -28,4 +29,5 @@ pub fn progress_fill_style(palette: &Palette, pct: f64) -> Style {
         .width_pct(pct)
         .background(c.success)
         .border_radius(sp.radius_md)
+        .apply_opt(palette.motion.normal(), |s, t| s.transition(Width, t))
 }
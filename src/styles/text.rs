@@ -0,0 +1,65 @@
+//! Text style recipes
+
+use floem::style::Style;
+
+use crate::palette::Palette;
+
+/// Style recipe for page and dialog titles.
+pub fn title_text_style(palette: &Palette) -> Style {
+    let t = &palette.typography;
+
+    Style::new()
+        .font_family(t.family_stack(t.font_heading_family))
+        .font_size(t.font_title)
+        .font_weight(t.weight_title)
+        .line_height(t.line_height_tight)
+        .color(palette.colors.text_primary)
+}
+
+/// Style recipe for section headings.
+pub fn heading_text_style(palette: &Palette) -> Style {
+    let t = &palette.typography;
+
+    Style::new()
+        .font_family(t.family_stack(t.font_heading_family))
+        .font_size(t.font_heading)
+        .font_weight(t.weight_heading)
+        .line_height(t.line_height_tight)
+        .color(palette.colors.text_primary)
+}
+
+/// Style recipe for body text.
+pub fn body_text_style(palette: &Palette) -> Style {
+    let t = &palette.typography;
+
+    Style::new()
+        .font_family(t.family_stack(t.font_ui))
+        .font_size(t.font_body)
+        .font_weight(t.weight_body)
+        .line_height(t.line_height_normal)
+        .color(palette.colors.text_primary)
+}
+
+/// Style recipe for captions, hints, and secondary labels.
+pub fn caption_text_style(palette: &Palette) -> Style {
+    let t = &palette.typography;
+
+    Style::new()
+        .font_family(t.family_stack(t.font_ui))
+        .font_size(t.font_small)
+        .font_weight(t.weight_caption)
+        .line_height(t.line_height_normal)
+        .color(palette.colors.text_secondary)
+}
+
+/// Style recipe for inline code and code blocks.
+pub fn code_text_style(palette: &Palette) -> Style {
+    let t = &palette.typography;
+
+    Style::new()
+        .font_family(t.family_stack(t.font_mono))
+        .font_size(t.font_body)
+        .font_weight(t.weight_body)
+        .line_height(t.line_height_normal)
+        .color(palette.colors.text_primary)
+}
@@ -3,7 +3,7 @@
 use floem::style::Style;
 
 use crate::palette::Palette;
-use crate::variant::Variant;
+use crate::variant::{Shape, Variant};
 
 /// Style recipe for toggle switch containers.
 ///
@@ -12,14 +12,7 @@ pub fn toggle_style(palette: &Palette, variant: Variant) -> Style {
     let c = &palette.colors;
     let sp = &palette.spacing;
 
-    let accent = match variant {
-        Variant::Primary => c.accent,
-        Variant::Success => c.success,
-        Variant::Warning => c.warning,
-        Variant::Error => c.error,
-        Variant::Info => c.info,
-        _ => c.accent,
-    };
+    let accent = variant.accent(palette);
 
     Style::new()
         .items_center()
@@ -33,3 +26,30 @@ pub fn toggle_style(palette: &Palette, variant: Variant) -> Style {
                 .cursor(floem::style::CursorStyle::Default)
         })
 }
+
+/// Style recipe for the switch track of a toggle.
+///
+/// Uses the variant accent while `on`, and rounds the track per
+/// `palette.shape` (fully rounded unless the shape is `Shape::Square`).
+pub fn toggle_track_style(palette: &Palette, variant: Variant, on: bool) -> Style {
+    toggle_track_style_shaped(palette, variant, on, palette.shape)
+}
+
+/// [`toggle_track_style`] with corners from `shape` instead of
+/// `palette.shape`.
+pub fn toggle_track_style_shaped(
+    palette: &Palette,
+    variant: Variant,
+    on: bool,
+    shape: Shape,
+) -> Style {
+    let track = if on {
+        variant.accent(palette)
+    } else {
+        palette.colors.border_strong
+    };
+
+    Style::new()
+        .background(track)
+        .border_radius(shape.radius(Shape::PILL_RADIUS))
+}
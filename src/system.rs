@@ -1,4 +1,4 @@
-//! OS appearance detection
+//! OS appearance, motion preference, and clock detection
 
 /// Check if the system is in dark mode.
 ///
@@ -61,3 +61,79 @@ pub fn is_system_dark_mode() -> bool {
 pub fn is_system_dark_mode() -> bool {
     true // Default to dark on unknown platforms
 }
+
+/// Check if the user asked for reduced motion.
+///
+/// The `FLEEM_REDUCED_MOTION` environment variable (`1`/`true` or
+/// `0`/`false`) takes precedence. Otherwise reads the OS accessibility
+/// setting; defaults to `false` when it can't be read.
+pub fn is_system_reduced_motion() -> bool {
+    match std::env::var("FLEEM_REDUCED_MOTION").as_deref() {
+        Ok("1") | Ok("true") => true,
+        Ok("0") | Ok("false") => false,
+        _ => os_reduced_motion(),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn os_reduced_motion() -> bool {
+    use std::process::Command;
+    Command::new("defaults")
+        .args(["read", "com.apple.universalaccess", "reduceMotion"])
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim() == "1")
+        .unwrap_or(false)
+}
+
+#[cfg(target_os = "linux")]
+fn os_reduced_motion() -> bool {
+    use std::process::Command;
+    // GNOME's animation switch, exposed through the settings portal
+    Command::new("dbus-send")
+        .args([
+            "--session",
+            "--print-reply=literal",
+            "--dest=org.freedesktop.portal.Desktop",
+            "/org/freedesktop/portal/desktop",
+            "org.freedesktop.portal.Settings.Read",
+            "string:org.gnome.desktop.interface",
+            "string:enable-animations",
+        ])
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).contains("boolean false"))
+        .unwrap_or(false)
+}
+
+#[cfg(target_os = "windows")]
+fn os_reduced_motion() -> bool {
+    use std::process::Command;
+    // MinAnimate 0 means "Animate controls and elements inside windows" is off
+    Command::new("reg")
+        .args([
+            "query",
+            r"HKCU\Control Panel\Desktop\WindowMetrics",
+            "/v",
+            "MinAnimate",
+        ])
+        .output()
+        .map(|o| {
+            let s = String::from_utf8_lossy(&o.stdout);
+            s.contains("REG_SZ    0")
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+fn os_reduced_motion() -> bool {
+    false
+}
+
+/// Read the local wall-clock time as `(hour, minute)`.
+///
+/// Computed in-process from the system time zone, so it is cheap enough to
+/// call while resolving styles.
+pub fn local_time() -> (u8, u8) {
+    use chrono::Timelike;
+    let now = chrono::Local::now();
+    (now.hour() as u8, now.minute() as u8)
+}
@@ -1,12 +1,20 @@
 //! Theme system — trait, appearance enum, and global reactive state
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::OnceLock;
 
 use floem_reactive::{RwSignal, SignalGet};
 use serde::{Deserialize, Serialize};
 
+use crate::density::{density, Density};
+use crate::fonts;
+use crate::motion::reduced_motion;
 use crate::palette::Palette;
+use crate::scale::ui_scale;
+use crate::schedule::{self, TimeOfDay};
+use crate::scope::{current_scope, ThemeScope};
 use crate::system::is_system_dark_mode;
+use crate::transition;
 
 /// Trait for defining a custom theme.
 ///
@@ -19,6 +27,16 @@ pub trait ThemeDef: Send + Sync + 'static {
     fn light_palette(&self) -> Palette;
     /// A human-readable name for this theme.
     fn name(&self) -> &str;
+
+    /// Font files (TTF/OTF bytes) shipped with this theme.
+    ///
+    /// They are registered with floem's text system when the theme is
+    /// installed, so families named in the palette's `TypographyTokens`
+    /// render even where they aren't installed. Usually filled with
+    /// `include_bytes!`.
+    fn fonts(&self) -> &[&'static [u8]] {
+        &[]
+    }
 }
 
 /// User-facing appearance preference.
@@ -28,17 +46,27 @@ pub enum Appearance {
     Dark,
     Light,
     System,
+    /// Light from `light_from` until `dark_from`, dark otherwise, by local
+    /// time. See [`set_clock`](crate::set_clock) to inject the time.
+    Scheduled {
+        light_from: TimeOfDay,
+        dark_from: TimeOfDay,
+    },
 }
 
 /// Resolved appearance (no `System` variant).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ResolvedAppearance {
     Dark,
     Light,
 }
 
 impl Appearance {
-    /// Resolve `System` to the actual OS appearance.
+    /// Resolve `System` to the actual OS appearance and `Scheduled` to the
+    /// period the current time falls in.
+    ///
+    /// For `Scheduled`, this subscribes reactive callers to periodic clock
+    /// re-checks, so style closures update when a boundary passes.
     pub fn resolve(self) -> ResolvedAppearance {
         match self {
             Appearance::Dark => ResolvedAppearance::Dark,
@@ -50,10 +78,36 @@ impl Appearance {
                     ResolvedAppearance::Light
                 }
             }
+            Appearance::Scheduled {
+                light_from,
+                dark_from,
+            } => {
+                schedule::track_schedule();
+                scheduled(light_from, dark_from, schedule::time_of_day())
+            }
+        }
+    }
+
+    /// Resolve as [`Self::resolve`] does, with `now` as the local time.
+    pub fn resolve_at(self, now: TimeOfDay) -> ResolvedAppearance {
+        match self {
+            Appearance::Scheduled {
+                light_from,
+                dark_from,
+            } => scheduled(light_from, dark_from, now),
+            other => other.resolve(),
         }
     }
 }
 
+fn scheduled(light_from: TimeOfDay, dark_from: TimeOfDay, now: TimeOfDay) -> ResolvedAppearance {
+    if schedule::is_light_at(light_from, dark_from, now) {
+        ResolvedAppearance::Light
+    } else {
+        ResolvedAppearance::Dark
+    }
+}
+
 // -- Built-in default theme --------------------------------------------------
 
 struct DefaultTheme;
@@ -73,12 +127,16 @@ impl ThemeDef for DefaultTheme {
 // -- Global state -------------------------------------------------------------
 
 struct ThemeState {
+    id: u64,
     theme: Box<dyn ThemeDef>,
     appearance: RwSignal<Appearance>,
 }
 
 static THEME_STATE: OnceLock<ThemeState> = OnceLock::new();
 
+/// Source of unique theme ids. Id 0 is reserved for the uninitialized fallback.
+static NEXT_THEME_ID: AtomicU64 = AtomicU64::new(1);
+
 /// Initialize the global theme with the built-in default palettes.
 ///
 /// Returns the reactive `Appearance` signal. Call once at app startup.
@@ -92,6 +150,8 @@ pub fn init(appearance: Appearance) -> RwSignal<Appearance> {
 
 /// Initialize the global theme with a custom `ThemeDef`.
 ///
+/// Fonts bundled by the theme (see [`ThemeDef::fonts`]) are registered here.
+///
 /// Returns the reactive `Appearance` signal. Call once at app startup.
 ///
 /// # Panics
@@ -99,7 +159,9 @@ pub fn init(appearance: Appearance) -> RwSignal<Appearance> {
 /// Panics if called outside a reactive runtime.
 pub fn init_with(theme: impl ThemeDef, appearance: Appearance) -> RwSignal<Appearance> {
     let signal = RwSignal::new(appearance);
+    fonts::register(&theme);
     let _ = THEME_STATE.set(ThemeState {
+        id: next_theme_id(),
         theme: Box::new(theme),
         appearance: signal,
     });
@@ -119,16 +181,81 @@ pub fn appearance_signal() -> Option<RwSignal<Appearance>> {
 /// `.style(|s| ...)` closure creates a reactive dependency — the closure
 /// re-runs when the appearance changes.
 ///
-/// Falls back to `Palette::dark()` if `init()` has not been called.
+/// Inside a [`theme_scope`](crate::theme_scope) this resolves to the nearest
+/// scope's theme and appearance, also when a style closure re-runs after the
+/// subtree was built. Falls back to `Palette::dark()` if `init()`
+/// has not been called.
 pub fn palette() -> Palette {
-    match THEME_STATE.get() {
-        Some(state) => {
-            let resolved = state.appearance.get().resolve();
-            match resolved {
-                ResolvedAppearance::Dark => state.theme.dark_palette(),
-                ResolvedAppearance::Light => state.theme.light_palette(),
-            }
+    palette_in(current_scope())
+}
+
+/// Resolve the palette for an optional scope, falling back to global state.
+pub(crate) fn palette_in(scope: Option<ThemeScope>) -> Palette {
+    match scope {
+        Some(scope) => scope.palette(),
+        None => {
+            let resolved = global_appearance().resolve();
+            let key = (global_theme_id(), resolved);
+            transition::apply(0, key, global_palette(resolved, density()))
         }
-        None => Palette::dark(),
     }
 }
+
+/// Identify the active theme and resolved appearance.
+///
+/// Reads the reactive `Appearance` signal, so callers inside style closures
+/// re-run when it changes. Used as the invalidation key for cached styles.
+pub(crate) fn theme_key() -> (u64, ResolvedAppearance) {
+    match current_scope() {
+        Some(scope) => scope.key(),
+        None => (global_theme_id(), global_appearance().resolve()),
+    }
+}
+
+/// Allocate a unique theme id.
+pub(crate) fn next_theme_id() -> u64 {
+    NEXT_THEME_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Id of the global theme, or 0 before `init()`.
+pub(crate) fn global_theme_id() -> u64 {
+    THEME_STATE.get().map_or(0, |s| s.id)
+}
+
+/// The global appearance preference (reactive), or `Dark` before `init()`.
+pub(crate) fn global_appearance() -> Appearance {
+    THEME_STATE
+        .get()
+        .map_or(Appearance::Dark, |s| s.appearance.get())
+}
+
+/// Palette of the global theme for a resolved appearance and density.
+pub(crate) fn global_palette(resolved: ResolvedAppearance, density: Density) -> Palette {
+    match THEME_STATE.get() {
+        Some(state) => theme_palette(state.theme.as_ref(), resolved, density),
+        None => theme_palette(&DefaultTheme, resolved, density),
+    }
+}
+
+/// Pick the dark or light palette from a theme and apply density and
+/// app-wide preferences on top of it.
+pub(crate) fn theme_palette(
+    theme: &dyn ThemeDef,
+    resolved: ResolvedAppearance,
+    density: Density,
+) -> Palette {
+    let mut palette = match resolved {
+        ResolvedAppearance::Dark => theme.dark_palette(),
+        ResolvedAppearance::Light => theme.light_palette(),
+    };
+    let scale = ui_scale().factor();
+    palette.typography = palette.typography.scaled(scale);
+    palette.spacing = palette.spacing.with_density(density).scaled(scale);
+    palette.components = palette.components.with_density(density).scaled(scale);
+    palette.elevation = palette.elevation.scaled(scale);
+    palette.colors = palette.colors.with_derived_hovers();
+    if reduced_motion() {
+        palette.motion = palette.motion.reduced();
+    }
+    palette
+}
@@ -2,6 +2,8 @@
 
 use floem::prelude::Color;
 
+use crate::blend::lerp_color;
+
 /// All semantic color slots for a theme palette.
 ///
 /// Covers backgrounds, text, borders, and semantic status colors.
@@ -59,6 +61,8 @@ pub struct ColorTokens {
     // Semantic: Success
     /// Success color
     pub success: Color,
+    /// Success hover state (derived from `success` when transparent or equal to it)
+    pub success_hover: Color,
     /// Success background (tinted)
     pub success_bg: Color,
     /// Foreground on success background
@@ -67,6 +71,8 @@ pub struct ColorTokens {
     // Semantic: Error
     /// Error/danger color
     pub error: Color,
+    /// Error hover state (derived from `error` when transparent or equal to it)
+    pub error_hover: Color,
     /// Error background (tinted)
     pub error_bg: Color,
     /// Foreground on error background
@@ -75,6 +81,8 @@ pub struct ColorTokens {
     // Semantic: Warning
     /// Warning color
     pub warning: Color,
+    /// Warning hover state (derived from `warning` when transparent or equal to it)
+    pub warning_hover: Color,
     /// Warning background (tinted)
     pub warning_bg: Color,
     /// Foreground on warning background
@@ -83,6 +91,8 @@ pub struct ColorTokens {
     // Semantic: Info
     /// Info color
     pub info: Color,
+    /// Info hover state (derived from `info` when transparent or equal to it)
+    pub info_hover: Color,
     /// Info background (tinted)
     pub info_bg: Color,
     /// Foreground on info background
@@ -96,3 +106,143 @@ pub struct ColorTokens {
     /// Foreground on neutral background
     pub neutral_fg: Color,
 }
+
+impl ColorTokens {
+    /// Fill in semantic hover colors a theme left unset.
+    ///
+    /// A hover that is transparent or equal to its base color is replaced by
+    /// the base moved 15% toward `text_primary`, which lightens on dark
+    /// palettes and darkens on light ones. Resolved palettes get this
+    /// automatically, so hovering a filled `Success`/`Warning`/`Error`/`Info`
+    /// element always changes its color.
+    pub fn with_derived_hovers(mut self) -> Self {
+        let text = self.text_primary;
+        let derive = |base: Color, hover: &mut Color| {
+            if hover.a == 0 || *hover == base {
+                *hover = lerp_color(base, text, 0.15);
+            }
+        };
+        derive(self.success, &mut self.success_hover);
+        derive(self.warning, &mut self.warning_hover);
+        derive(self.error, &mut self.error_hover);
+        derive(self.info, &mut self.info_hover);
+        self
+    }
+
+    /// Interpolate every color token between `a` and `b` in OKLab.
+    ///
+    /// `t = 0.0` yields `a`, `t = 1.0` yields `b`; `t` is clamped to that
+    /// range. See [`crate::lerp_color`].
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            bg_base: lerp_color(a.bg_base, b.bg_base, t),
+            bg_surface: lerp_color(a.bg_surface, b.bg_surface, t),
+            bg_elevated: lerp_color(a.bg_elevated, b.bg_elevated, t),
+            bg_hover: lerp_color(a.bg_hover, b.bg_hover, t),
+            bg_selected: lerp_color(a.bg_selected, b.bg_selected, t),
+            bg_input: lerp_color(a.bg_input, b.bg_input, t),
+            bg_disabled: lerp_color(a.bg_disabled, b.bg_disabled, t),
+            bg_overlay: lerp_color(a.bg_overlay, b.bg_overlay, t),
+            text_primary: lerp_color(a.text_primary, b.text_primary, t),
+            text_secondary: lerp_color(a.text_secondary, b.text_secondary, t),
+            text_muted: lerp_color(a.text_muted, b.text_muted, t),
+            text_inverse: lerp_color(a.text_inverse, b.text_inverse, t),
+            text_disabled: lerp_color(a.text_disabled, b.text_disabled, t),
+            text_link: lerp_color(a.text_link, b.text_link, t),
+            border: lerp_color(a.border, b.border, t),
+            border_strong: lerp_color(a.border_strong, b.border_strong, t),
+            border_focus: lerp_color(a.border_focus, b.border_focus, t),
+            accent: lerp_color(a.accent, b.accent, t),
+            accent_hover: lerp_color(a.accent_hover, b.accent_hover, t),
+            accent_fg: lerp_color(a.accent_fg, b.accent_fg, t),
+            success: lerp_color(a.success, b.success, t),
+            success_hover: lerp_color(a.success_hover, b.success_hover, t),
+            success_bg: lerp_color(a.success_bg, b.success_bg, t),
+            success_fg: lerp_color(a.success_fg, b.success_fg, t),
+            error: lerp_color(a.error, b.error, t),
+            error_hover: lerp_color(a.error_hover, b.error_hover, t),
+            error_bg: lerp_color(a.error_bg, b.error_bg, t),
+            error_fg: lerp_color(a.error_fg, b.error_fg, t),
+            warning: lerp_color(a.warning, b.warning, t),
+            warning_hover: lerp_color(a.warning_hover, b.warning_hover, t),
+            warning_bg: lerp_color(a.warning_bg, b.warning_bg, t),
+            warning_fg: lerp_color(a.warning_fg, b.warning_fg, t),
+            info: lerp_color(a.info, b.info, t),
+            info_hover: lerp_color(a.info_hover, b.info_hover, t),
+            info_bg: lerp_color(a.info_bg, b.info_bg, t),
+            info_fg: lerp_color(a.info_fg, b.info_fg, t),
+            neutral: lerp_color(a.neutral, b.neutral, t),
+            neutral_hover: lerp_color(a.neutral_hover, b.neutral_hover, t),
+            neutral_fg: lerp_color(a.neutral_fg, b.neutral_fg, t),
+        }
+    }
+}
+
+/// Name-based access to every color field, for [`super::ColorSpec`].
+macro_rules! named_fields {
+    ($($name:ident),* $(,)?) => {
+        impl ColorTokens {
+            /// Names of every color token, as written in specs and theme files.
+            pub const NAMES: &'static [&'static str] = &[$(stringify!($name)),*];
+
+            /// Look up a color token by name.
+            pub fn get(&self, name: &str) -> Option<Color> {
+                match name {
+                    $(stringify!($name) => Some(self.$name),)*
+                    _ => None,
+                }
+            }
+
+            /// Set a color token by name. Returns `false` for unknown names.
+            pub fn set(&mut self, name: &str, color: Color) -> bool {
+                match name {
+                    $(stringify!($name) => self.$name = color,)*
+                    _ => return false,
+                }
+                true
+            }
+        }
+    };
+}
+
+named_fields!(
+    bg_base,
+    bg_surface,
+    bg_elevated,
+    bg_hover,
+    bg_selected,
+    bg_input,
+    bg_disabled,
+    bg_overlay,
+    text_primary,
+    text_secondary,
+    text_muted,
+    text_inverse,
+    text_disabled,
+    text_link,
+    border,
+    border_strong,
+    border_focus,
+    accent,
+    accent_hover,
+    accent_fg,
+    success,
+    success_hover,
+    success_bg,
+    success_fg,
+    error,
+    error_hover,
+    error_bg,
+    error_fg,
+    warning,
+    warning_hover,
+    warning_bg,
+    warning_fg,
+    info,
+    info_hover,
+    info_bg,
+    info_fg,
+    neutral,
+    neutral_hover,
+    neutral_fg,
+);
@@ -0,0 +1,451 @@
+//! Per-component tokens derived from the global palette
+
+use serde::{Deserialize, Serialize};
+
+use crate::density::Density;
+use crate::palette::Palette;
+use crate::variant::Size;
+
+/// Dimensions for one button size, consumed by `button_style`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ButtonTokens {
+    /// Vertical padding
+    pub pad_v: f32,
+    /// Horizontal padding
+    pub pad_h: f32,
+    /// Corner radius
+    pub radius: f32,
+    /// Label font size
+    pub font_size: f32,
+    /// Border width at rest
+    pub border_width: f32,
+    /// Border width while focused
+    pub border_width_focus: f32,
+}
+
+impl ButtonTokens {
+    /// Defaults from the global spacing and typography tokens.
+    ///
+    /// `radius` is the `Shape::Rounded` radius; [`Palette::button_tokens`]
+    /// applies the palette's shape to it.
+    pub fn derive(palette: &Palette, size: Size) -> Self {
+        let (pad_v, pad_h) = size.padding(palette);
+        Self {
+            pad_v,
+            pad_h,
+            radius: size.rounded_radius(palette),
+            font_size: size.font_size(palette),
+            border_width: palette.spacing.border_width,
+            border_width_focus: palette.spacing.border_width_thick,
+        }
+    }
+}
+
+/// Optional overrides for [`ButtonTokens`], applied to every size of a button.
+///
+/// Unset fields keep the derived value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ButtonTokensPatch {
+    pub pad_v: Option<f32>,
+    pub pad_h: Option<f32>,
+    pub radius: Option<f32>,
+    pub font_size: Option<f32>,
+    pub border_width: Option<f32>,
+    pub border_width_focus: Option<f32>,
+}
+
+impl ButtonTokensPatch {
+    /// `tokens` with every set field replaced.
+    pub fn apply(self, tokens: ButtonTokens) -> ButtonTokens {
+        ButtonTokens {
+            pad_v: self.pad_v.unwrap_or(tokens.pad_v),
+            pad_h: self.pad_h.unwrap_or(tokens.pad_h),
+            radius: self.radius.unwrap_or(tokens.radius),
+            font_size: self.font_size.unwrap_or(tokens.font_size),
+            border_width: self.border_width.unwrap_or(tokens.border_width),
+            border_width_focus: self.border_width_focus.unwrap_or(tokens.border_width_focus),
+        }
+    }
+
+    fn with_density(self, f: f32) -> Self {
+        Self {
+            pad_v: times(self.pad_v, f),
+            pad_h: times(self.pad_h, f),
+            ..self
+        }
+    }
+
+    fn scaled(self, factor: f32) -> Self {
+        Self {
+            pad_v: times(self.pad_v, factor),
+            pad_h: times(self.pad_h, factor),
+            radius: times(self.radius, factor),
+            font_size: times(self.font_size, factor),
+            ..self
+        }
+    }
+}
+
+/// Dimensions for text inputs, consumed by `input_style`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputTokens {
+    /// Vertical padding
+    pub pad_v: f32,
+    /// Horizontal padding
+    pub pad_h: f32,
+    /// Corner radius
+    pub radius: f32,
+    /// Text font size
+    pub font_size: f32,
+    /// Minimum width
+    pub min_width: f32,
+    /// Border width
+    pub border_width: f32,
+}
+
+impl InputTokens {
+    /// Defaults from the global spacing and typography tokens.
+    ///
+    /// `radius` is the `Shape::Rounded` radius; [`Palette::input_tokens`]
+    /// applies the palette's shape to it.
+    pub fn derive(palette: &Palette) -> Self {
+        let sp = &palette.spacing;
+        Self {
+            pad_v: sp.pad_sm,
+            pad_h: sp.pad_md,
+            radius: sp.radius_md,
+            font_size: palette.typography.font_body,
+            min_width: sp.input_min_width,
+            border_width: sp.border_width,
+        }
+    }
+}
+
+/// Optional overrides for [`InputTokens`], applied to text inputs.
+///
+/// Unset fields keep the derived value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InputTokensPatch {
+    pub pad_v: Option<f32>,
+    pub pad_h: Option<f32>,
+    pub radius: Option<f32>,
+    pub font_size: Option<f32>,
+    pub min_width: Option<f32>,
+    pub border_width: Option<f32>,
+}
+
+impl InputTokensPatch {
+    /// `tokens` with every set field replaced.
+    pub fn apply(self, tokens: InputTokens) -> InputTokens {
+        InputTokens {
+            pad_v: self.pad_v.unwrap_or(tokens.pad_v),
+            pad_h: self.pad_h.unwrap_or(tokens.pad_h),
+            radius: self.radius.unwrap_or(tokens.radius),
+            font_size: self.font_size.unwrap_or(tokens.font_size),
+            min_width: self.min_width.unwrap_or(tokens.min_width),
+            border_width: self.border_width.unwrap_or(tokens.border_width),
+        }
+    }
+
+    fn with_density(self, f: f32) -> Self {
+        Self {
+            pad_v: times(self.pad_v, f),
+            pad_h: times(self.pad_h, f),
+            ..self
+        }
+    }
+
+    fn scaled(self, factor: f32) -> Self {
+        Self {
+            pad_v: times(self.pad_v, factor),
+            pad_h: times(self.pad_h, factor),
+            radius: times(self.radius, factor),
+            font_size: times(self.font_size, factor),
+            min_width: times(self.min_width, factor),
+            ..self
+        }
+    }
+}
+
+/// Dimensions for cards, consumed by `card_style`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CardTokens {
+    /// Padding on every side
+    pub padding: f32,
+    /// Corner radius
+    pub radius: f32,
+    /// Border width
+    pub border_width: f32,
+    /// Elevation level of the shadow
+    pub elevation: u8,
+}
+
+impl CardTokens {
+    /// Defaults from the global spacing tokens.
+    pub fn derive(palette: &Palette) -> Self {
+        let sp = &palette.spacing;
+        Self {
+            padding: sp.pad_xl,
+            radius: sp.radius_xl,
+            border_width: sp.border_width,
+            elevation: 1,
+        }
+    }
+}
+
+/// Optional overrides for [`CardTokens`], applied to cards.
+///
+/// Unset fields keep the derived value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CardTokensPatch {
+    pub padding: Option<f32>,
+    pub radius: Option<f32>,
+    pub border_width: Option<f32>,
+    pub elevation: Option<u8>,
+}
+
+impl CardTokensPatch {
+    /// `tokens` with every set field replaced.
+    pub fn apply(self, tokens: CardTokens) -> CardTokens {
+        CardTokens {
+            padding: self.padding.unwrap_or(tokens.padding),
+            radius: self.radius.unwrap_or(tokens.radius),
+            border_width: self.border_width.unwrap_or(tokens.border_width),
+            elevation: self.elevation.unwrap_or(tokens.elevation),
+        }
+    }
+
+    fn with_density(self, f: f32) -> Self {
+        Self {
+            padding: times(self.padding, f),
+            ..self
+        }
+    }
+
+    fn scaled(self, factor: f32) -> Self {
+        Self {
+            padding: times(self.padding, factor),
+            radius: times(self.radius, factor),
+            ..self
+        }
+    }
+}
+
+/// Dimensions for badges, consumed by `badge_style` and `badge_fill_style`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BadgeTokens {
+    /// Vertical padding
+    pub pad_v: f32,
+    /// Horizontal padding
+    pub pad_h: f32,
+    /// Corner radius
+    pub radius: f32,
+    /// Text font size
+    pub font_size: f32,
+}
+
+impl BadgeTokens {
+    /// Defaults from the global spacing and typography tokens.
+    ///
+    /// `radius` is the `Shape::Rounded` radius; [`Palette::badge_tokens`]
+    /// applies the palette's shape to it.
+    pub fn derive(palette: &Palette) -> Self {
+        let sp = &palette.spacing;
+        Self {
+            pad_v: sp.pad_sm,
+            pad_h: sp.pad_lg,
+            radius: sp.radius_md,
+            font_size: palette.typography.font_body,
+        }
+    }
+}
+
+/// Optional overrides for [`BadgeTokens`], applied to badges.
+///
+/// Unset fields keep the derived value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BadgeTokensPatch {
+    pub pad_v: Option<f32>,
+    pub pad_h: Option<f32>,
+    pub radius: Option<f32>,
+    pub font_size: Option<f32>,
+}
+
+impl BadgeTokensPatch {
+    /// `tokens` with every set field replaced.
+    pub fn apply(self, tokens: BadgeTokens) -> BadgeTokens {
+        BadgeTokens {
+            pad_v: self.pad_v.unwrap_or(tokens.pad_v),
+            pad_h: self.pad_h.unwrap_or(tokens.pad_h),
+            radius: self.radius.unwrap_or(tokens.radius),
+            font_size: self.font_size.unwrap_or(tokens.font_size),
+        }
+    }
+
+    fn with_density(self, f: f32) -> Self {
+        Self {
+            pad_v: times(self.pad_v, f),
+            pad_h: times(self.pad_h, f),
+            ..self
+        }
+    }
+
+    fn scaled(self, factor: f32) -> Self {
+        Self {
+            pad_v: times(self.pad_v, factor),
+            pad_h: times(self.pad_h, factor),
+            radius: times(self.radius, factor),
+            font_size: times(self.font_size, factor),
+        }
+    }
+}
+
+/// Dimensions for modal cards, consumed by `modal_card_style`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModalTokens {
+    /// Padding on every side
+    pub padding: f32,
+    /// Corner radius
+    pub radius: f32,
+    /// Card width
+    pub width: f32,
+    /// Border width
+    pub border_width: f32,
+    /// Elevation level of the shadow
+    pub elevation: u8,
+}
+
+impl ModalTokens {
+    /// Defaults from the global spacing tokens.
+    pub fn derive(palette: &Palette) -> Self {
+        let sp = &palette.spacing;
+        Self {
+            padding: sp.pad_xl * 1.5,
+            radius: sp.radius_xl,
+            width: sp.modal_width,
+            border_width: sp.border_width,
+            elevation: 4,
+        }
+    }
+}
+
+/// Optional overrides for [`ModalTokens`], applied to modal cards.
+///
+/// Unset fields keep the derived value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModalTokensPatch {
+    pub padding: Option<f32>,
+    pub radius: Option<f32>,
+    pub width: Option<f32>,
+    pub border_width: Option<f32>,
+    pub elevation: Option<u8>,
+}
+
+impl ModalTokensPatch {
+    /// `tokens` with every set field replaced.
+    pub fn apply(self, tokens: ModalTokens) -> ModalTokens {
+        ModalTokens {
+            padding: self.padding.unwrap_or(tokens.padding),
+            radius: self.radius.unwrap_or(tokens.radius),
+            width: self.width.unwrap_or(tokens.width),
+            border_width: self.border_width.unwrap_or(tokens.border_width),
+            elevation: self.elevation.unwrap_or(tokens.elevation),
+        }
+    }
+
+    fn with_density(self, f: f32) -> Self {
+        Self {
+            padding: times(self.padding, f),
+            ..self
+        }
+    }
+
+    fn scaled(self, factor: f32) -> Self {
+        Self {
+            padding: times(self.padding, factor),
+            radius: times(self.radius, factor),
+            width: times(self.width, factor),
+            ..self
+        }
+    }
+}
+
+/// Per-theme overrides for component tokens.
+///
+/// Each override is a patch of optional values merged over the component's
+/// `derive`; `None` uses the derived tokens unchanged. Patched dimensions are
+/// logical pixels at comfortable density and UI scale 1, and get scaled like
+/// the shared tokens. A patched button, input, or badge `radius` replaces the
+/// rounded radius, so square and pill shapes still apply. Resolve with
+/// [`Palette::button_tokens`] and friends.
+///
+/// ```rust
+/// use fleem::tokens::{ButtonTokensPatch, ComponentTokens};
+/// use fleem::Palette;
+///
+/// let mut square = Palette::dark();
+/// square.components.button = Some(ButtonTokensPatch {
+///     radius: Some(0.0),
+///     ..Default::default()
+/// });
+/// assert_eq!(square.button_tokens(fleem::Size::Large).radius, 0.0);
+///
+/// // Patches are plain data, so theme files can declare them.
+/// let components: ComponentTokens = serde_json::from_str(r#"{"card": {"radius": 0.0}}"#).unwrap();
+/// assert_eq!(components.card.unwrap().radius, Some(0.0));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ComponentTokens {
+    pub button: Option<ButtonTokensPatch>,
+    pub input: Option<InputTokensPatch>,
+    pub card: Option<CardTokensPatch>,
+    pub badge: Option<BadgeTokensPatch>,
+    pub modal: Option<ModalTokensPatch>,
+}
+
+impl ComponentTokens {
+    /// No overrides: every component derives from the global tokens.
+    pub const DERIVED: Self = Self {
+        button: None,
+        input: None,
+        card: None,
+        badge: None,
+        modal: None,
+    };
+
+    /// Scale patched paddings for `density`, like
+    /// [`SpacingTokens::with_density`](super::SpacingTokens::with_density).
+    pub fn with_density(self, density: Density) -> Self {
+        let f = density.factor();
+        Self {
+            button: self.button.map(|p| p.with_density(f)),
+            input: self.input.map(|p| p.with_density(f)),
+            card: self.card.map(|p| p.with_density(f)),
+            badge: self.badge.map(|p| p.with_density(f)),
+            modal: self.modal.map(|p| p.with_density(f)),
+        }
+    }
+
+    /// Multiply patched paddings, radii, font sizes, and dimensions by
+    /// `factor`. Border widths and elevation levels are kept.
+    pub fn scaled(self, factor: f32) -> Self {
+        Self {
+            button: self.button.map(|p| p.scaled(factor)),
+            input: self.input.map(|p| p.scaled(factor)),
+            card: self.card.map(|p| p.scaled(factor)),
+            badge: self.badge.map(|p| p.scaled(factor)),
+            modal: self.modal.map(|p| p.scaled(factor)),
+        }
+    }
+}
+
+/// `value * factor`, if set.
+fn times(value: Option<f32>, factor: f32) -> Option<f32> {
+    value.map(|v| v * factor)
+}
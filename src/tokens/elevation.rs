@@ -0,0 +1,120 @@
+//! Elevation tokens for depth and shadows
+
+use floem::prelude::Color;
+
+use crate::blend::{lerp_color, lerp_f32};
+
+/// A single box shadow.
+///
+/// Values are in logical pixels (f32).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shadow {
+    /// Blur radius
+    pub blur: f32,
+    /// Horizontal offset
+    pub offset_x: f32,
+    /// Vertical offset
+    pub offset_y: f32,
+    /// Spread radius
+    pub spread: f32,
+    /// Shadow color
+    pub color: Color,
+}
+
+impl Shadow {
+    /// A shadow that draws nothing.
+    pub const NONE: Self = Self {
+        blur: 0.0,
+        offset_x: 0.0,
+        offset_y: 0.0,
+        spread: 0.0,
+        color: Color::TRANSPARENT,
+    };
+
+    /// Whether this shadow draws anything.
+    pub fn is_visible(&self) -> bool {
+        self.color.a > 0
+            && (self.blur > 0.0
+                || self.spread > 0.0
+                || self.offset_x != 0.0
+                || self.offset_y != 0.0)
+    }
+
+    /// Multiply blur, offsets, and spread by `factor`.
+    pub fn scaled(self, factor: f32) -> Self {
+        Self {
+            blur: self.blur * factor,
+            offset_x: self.offset_x * factor,
+            offset_y: self.offset_y * factor,
+            spread: self.spread * factor,
+            color: self.color,
+        }
+    }
+
+    /// Interpolate every field between `a` and `b`; color blends in OKLab.
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            blur: lerp_f32(a.blur, b.blur, t),
+            offset_x: lerp_f32(a.offset_x, b.offset_x, t),
+            offset_y: lerp_f32(a.offset_y, b.offset_y, t),
+            spread: lerp_f32(a.spread, b.spread, t),
+            color: lerp_color(a.color, b.color, t),
+        }
+    }
+}
+
+/// Shadow per elevation level for a theme palette.
+///
+/// Level 0 is flat; higher levels sit further above the surface. Shadows
+/// are darker in dark palettes so they stay visible on dark backgrounds.
+#[derive(Debug, Clone, Copy)]
+pub struct ElevationTokens {
+    /// Flat, no shadow
+    pub level0: Shadow,
+    /// Resting cards and panels
+    pub level1: Shadow,
+    /// Menus and dropdowns
+    pub level2: Shadow,
+    /// Tooltips and popovers
+    pub level3: Shadow,
+    /// Modals and dialogs
+    pub level4: Shadow,
+}
+
+impl ElevationTokens {
+    /// Highest defined level.
+    pub const MAX_LEVEL: u8 = 4;
+
+    /// Shadow for `level`, clamped to [`Self::MAX_LEVEL`].
+    pub fn get(&self, level: u8) -> Shadow {
+        match level {
+            0 => self.level0,
+            1 => self.level1,
+            2 => self.level2,
+            3 => self.level3,
+            _ => self.level4,
+        }
+    }
+
+    /// Scale every level's shadow geometry by `factor`.
+    pub fn scaled(self, factor: f32) -> Self {
+        Self {
+            level0: self.level0.scaled(factor),
+            level1: self.level1.scaled(factor),
+            level2: self.level2.scaled(factor),
+            level3: self.level3.scaled(factor),
+            level4: self.level4.scaled(factor),
+        }
+    }
+
+    /// Interpolate every level between `a` and `b`.
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            level0: Shadow::lerp(&a.level0, &b.level0, t),
+            level1: Shadow::lerp(&a.level1, &b.level1, t),
+            level2: Shadow::lerp(&a.level2, &b.level2, t),
+            level3: Shadow::lerp(&a.level3, &b.level3, t),
+            level4: Shadow::lerp(&a.level4, &b.level4, t),
+        }
+    }
+}
@@ -1,9 +1,23 @@
-//! Design token structs for color, typography, and spacing
+//! Design token structs for color, typography, spacing, motion, elevation,
+//! and individual components
 
 mod color;
+mod component;
+mod elevation;
+mod motion;
 mod spacing;
+mod spec;
+mod type_scale;
 mod typography;
 
 pub use color::ColorTokens;
+pub use component::{
+    BadgeTokens, BadgeTokensPatch, ButtonTokens, ButtonTokensPatch, CardTokens, CardTokensPatch,
+    ComponentTokens, InputTokens, InputTokensPatch, ModalTokens, ModalTokensPatch,
+};
+pub use elevation::{ElevationTokens, Shadow};
+pub use motion::{Easing, MotionTokens};
 pub use spacing::SpacingTokens;
+pub use spec::{ColorSpec, TokenError};
+pub use type_scale::TypeScale;
 pub use typography::TypographyTokens;
@@ -0,0 +1,107 @@
+//! Motion tokens for transition timing
+
+use std::time::Duration;
+
+use floem::easing::Bezier;
+use floem::style::Transition;
+
+use crate::blend::lerp_f32;
+
+/// Easing curve for a transition.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Easing {
+    Linear,
+    EaseIn,
+    EaseOut,
+    #[default]
+    EaseInOut,
+}
+
+/// Transition durations and easing curves for a theme palette.
+///
+/// Durations collapse to zero when reduced motion is requested, in which
+/// case the transition helpers return `None` and recipes skip transitions.
+#[derive(Debug, Clone, Copy)]
+pub struct MotionTokens {
+    /// Small state changes like hover and press (100ms)
+    pub duration_fast: Duration,
+    /// Standard transitions (200ms)
+    pub duration_normal: Duration,
+    /// Large or attention-drawing transitions (300ms)
+    pub duration_slow: Duration,
+    /// Easing for elements changing in place (ease-in-out)
+    pub easing_standard: Easing,
+    /// Easing for elements entering or growing (ease-out)
+    pub easing_enter: Easing,
+    /// Easing for elements leaving or shrinking (ease-in)
+    pub easing_exit: Easing,
+}
+
+impl MotionTokens {
+    /// Default motion timings shared by the built-in palettes.
+    pub const fn standard() -> Self {
+        Self {
+            duration_fast: Duration::from_millis(100),
+            duration_normal: Duration::from_millis(200),
+            duration_slow: Duration::from_millis(300),
+            easing_standard: Easing::EaseInOut,
+            easing_enter: Easing::EaseOut,
+            easing_exit: Easing::EaseIn,
+        }
+    }
+
+    /// These tokens with every duration set to zero.
+    pub const fn reduced(self) -> Self {
+        Self {
+            duration_fast: Duration::ZERO,
+            duration_normal: Duration::ZERO,
+            duration_slow: Duration::ZERO,
+            ..self
+        }
+    }
+
+    /// A floem `Transition`, or `None` if `duration` is zero.
+    pub fn transition(&self, duration: Duration, easing: Easing) -> Option<Transition> {
+        if duration.is_zero() {
+            return None;
+        }
+        Some(match easing {
+            Easing::Linear => Transition::linear(duration),
+            Easing::EaseIn => Transition::new(duration, Bezier::ease_in()),
+            Easing::EaseOut => Transition::new(duration, Bezier::ease_out()),
+            Easing::EaseInOut => Transition::ease_in_out(duration),
+        })
+    }
+
+    /// Fast transition with the standard easing.
+    pub fn fast(&self) -> Option<Transition> {
+        self.transition(self.duration_fast, self.easing_standard)
+    }
+
+    /// Normal transition with the standard easing.
+    pub fn normal(&self) -> Option<Transition> {
+        self.transition(self.duration_normal, self.easing_standard)
+    }
+
+    /// Slow transition with the standard easing.
+    pub fn slow(&self) -> Option<Transition> {
+        self.transition(self.duration_slow, self.easing_standard)
+    }
+
+    /// Interpolate durations between `a` and `b`.
+    ///
+    /// `t` is clamped to `0.0..=1.0`. Easing curves come from the nearer
+    /// endpoint.
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        let near = if t < 0.5 { a } else { b };
+        let lerp_duration = |a: Duration, b: Duration| {
+            Duration::from_secs_f32(lerp_f32(a.as_secs_f32(), b.as_secs_f32(), t))
+        };
+        Self {
+            duration_fast: lerp_duration(a.duration_fast, b.duration_fast),
+            duration_normal: lerp_duration(a.duration_normal, b.duration_normal),
+            duration_slow: lerp_duration(a.duration_slow, b.duration_slow),
+            ..*near
+        }
+    }
+}
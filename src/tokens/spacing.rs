@@ -1,5 +1,8 @@
 //! Spacing tokens for consistent layout
 
+use crate::blend::lerp_f32;
+use crate::density::Density;
+
 /// Spacing, radius, and dimension tokens for a theme palette.
 ///
 /// All values are in logical pixels (f32).
@@ -48,4 +51,83 @@ pub struct SpacingTokens {
     pub label_width: f32,
     /// Progress bar track height (8.0)
     pub progress_height: f32,
+    /// Modal card width (500.0)
+    pub modal_width: f32,
+    /// Smallest side of a `Size::Normal` hit target (32.0); see
+    /// [`crate::Size::min_target`]
+    pub hit_target: f32,
+}
+
+impl SpacingTokens {
+    /// Scale padding, gap, and hit target tokens for `density`.
+    ///
+    /// Radii, border widths, and other fixed dimensions are unchanged.
+    pub fn with_density(self, density: Density) -> Self {
+        let f = density.factor();
+        Self {
+            pad_xs: self.pad_xs * f,
+            pad_sm: self.pad_sm * f,
+            pad_md: self.pad_md * f,
+            pad_lg: self.pad_lg * f,
+            pad_xl: self.pad_xl * f,
+            gap_sm: self.gap_sm * f,
+            gap_md: self.gap_md * f,
+            gap_lg: self.gap_lg * f,
+            hit_target: self.hit_target * f,
+            ..self
+        }
+    }
+
+    /// Multiply padding, gaps, radii, and dimensions by `factor`.
+    ///
+    /// Border widths are kept so hairlines stay crisp.
+    pub fn scaled(self, factor: f32) -> Self {
+        Self {
+            pad_xs: self.pad_xs * factor,
+            pad_sm: self.pad_sm * factor,
+            pad_md: self.pad_md * factor,
+            pad_lg: self.pad_lg * factor,
+            pad_xl: self.pad_xl * factor,
+            gap_sm: self.gap_sm * factor,
+            gap_md: self.gap_md * factor,
+            gap_lg: self.gap_lg * factor,
+            radius_sm: self.radius_sm * factor,
+            radius_md: self.radius_md * factor,
+            radius_lg: self.radius_lg * factor,
+            radius_xl: self.radius_xl * factor,
+            input_min_width: self.input_min_width * factor,
+            label_width: self.label_width * factor,
+            progress_height: self.progress_height * factor,
+            modal_width: self.modal_width * factor,
+            hit_target: self.hit_target * factor,
+            ..self
+        }
+    }
+
+    /// Interpolate every dimension between `a` and `b`.
+    ///
+    /// `t` is clamped to `0.0..=1.0`.
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        Self {
+            pad_xs: lerp_f32(a.pad_xs, b.pad_xs, t),
+            pad_sm: lerp_f32(a.pad_sm, b.pad_sm, t),
+            pad_md: lerp_f32(a.pad_md, b.pad_md, t),
+            pad_lg: lerp_f32(a.pad_lg, b.pad_lg, t),
+            pad_xl: lerp_f32(a.pad_xl, b.pad_xl, t),
+            gap_sm: lerp_f32(a.gap_sm, b.gap_sm, t),
+            gap_md: lerp_f32(a.gap_md, b.gap_md, t),
+            gap_lg: lerp_f32(a.gap_lg, b.gap_lg, t),
+            radius_sm: lerp_f32(a.radius_sm, b.radius_sm, t),
+            radius_md: lerp_f32(a.radius_md, b.radius_md, t),
+            radius_lg: lerp_f32(a.radius_lg, b.radius_lg, t),
+            radius_xl: lerp_f32(a.radius_xl, b.radius_xl, t),
+            border_width: lerp_f32(a.border_width, b.border_width, t),
+            border_width_thick: lerp_f32(a.border_width_thick, b.border_width_thick, t),
+            input_min_width: lerp_f32(a.input_min_width, b.input_min_width, t),
+            label_width: lerp_f32(a.label_width, b.label_width, t),
+            progress_height: lerp_f32(a.progress_height, b.progress_height, t),
+            modal_width: lerp_f32(a.modal_width, b.modal_width, t),
+            hit_target: lerp_f32(a.hit_target, b.hit_target, t),
+        }
+    }
 }
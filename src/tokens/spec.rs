@@ -0,0 +1,190 @@
+//! Color specs with token references, for theme files and builders
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use floem::prelude::Color;
+use serde::{Deserialize, Serialize};
+
+use super::ColorTokens;
+
+/// Color token overrides by name, where values are hex colors or references
+/// to other tokens.
+///
+/// A value is `#rrggbb`, `#rrggbbaa`, or `{token}`. References resolve to the
+/// referenced token's value in the same spec if it sets one, otherwise to
+/// the base palette's value, so `info = "{accent}"` keeps following `accent`
+/// when only `accent` changes. Serializes as a plain string map.
+///
+/// ```rust
+/// use fleem::tokens::ColorSpec;
+/// use fleem::Palette;
+///
+/// let spec = ColorSpec::new()
+///     .set("accent", "#8e24aa")
+///     .set("border_focus", "{accent}")
+///     .set("info", "{accent}");
+/// let palette = Palette::dark().with_color_spec(&spec).unwrap();
+/// assert_eq!(palette.colors.info, palette.colors.accent);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ColorSpec(BTreeMap<String, String>);
+
+/// References the built-in palettes' values already follow.
+const DEFAULT_ALIASES: [(&str, &str); 2] = [("info", "{accent}"), ("info_hover", "{accent_hover}")];
+
+/// Why a [`ColorSpec`] failed to resolve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenError {
+    /// The spec sets a token that doesn't exist.
+    UnknownToken { token: String },
+    /// A value references a token that doesn't exist.
+    UnknownReference { token: String, reference: String },
+    /// References form a loop; `path` starts and ends with the same token.
+    Cycle { path: Vec<String> },
+    /// A value is neither a hex color nor a `{token}` reference.
+    InvalidColor { token: String, value: String },
+}
+
+impl fmt::Display for TokenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenError::UnknownToken { token } => {
+                write!(f, "unknown color token `{token}`")
+            }
+            TokenError::UnknownReference { token, reference } => {
+                write!(f, "`{token}` references unknown token `{{{reference}}}`")
+            }
+            TokenError::Cycle { path } => {
+                write!(f, "reference cycle: {}", path.join(" -> "))
+            }
+            TokenError::InvalidColor { token, value } => write!(
+                f,
+                "`{token}` = `{value}` is not a `#rrggbb`, `#rrggbbaa`, or `{{token}}` value"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TokenError {}
+
+impl ColorSpec {
+    /// An empty spec.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set `token` to a hex color or `{token}` reference.
+    pub fn set(mut self, token: impl Into<String>, value: impl Into<String>) -> Self {
+        self.0.insert(token.into(), value.into());
+        self
+    }
+
+    /// Add the references the built-in palettes follow (`info` is `accent`,
+    /// `info_hover` is `accent_hover`) unless the spec sets those tokens.
+    ///
+    /// With these, a spec that only changes `accent` moves `info` along.
+    pub fn with_default_aliases(mut self) -> Self {
+        for (token, value) in DEFAULT_ALIASES {
+            self.0
+                .entry(token.to_string())
+                .or_insert_with(|| value.to_string());
+        }
+        self
+    }
+
+    /// Apply the spec on top of `base`, resolving references.
+    pub fn resolve(&self, base: &ColorTokens) -> Result<ColorTokens, TokenError> {
+        let mut resolver = Resolver {
+            spec: &self.0,
+            base,
+            done: HashMap::new(),
+            stack: Vec::new(),
+        };
+        let mut colors = *base;
+        for token in self.0.keys() {
+            let color = resolver.color(token)?;
+            colors.set(token, color);
+        }
+        Ok(colors)
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for ColorSpec {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        )
+    }
+}
+
+struct Resolver<'a> {
+    spec: &'a BTreeMap<String, String>,
+    base: &'a ColorTokens,
+    done: HashMap<&'a str, Color>,
+    /// Tokens being resolved, outermost first, for cycle detection.
+    stack: Vec<&'a str>,
+}
+
+impl<'a> Resolver<'a> {
+    fn color(&mut self, token: &'a str) -> Result<Color, TokenError> {
+        if let Some(color) = self.done.get(token) {
+            return Ok(*color);
+        }
+        let Some(base) = self.base.get(token) else {
+            return Err(TokenError::UnknownToken {
+                token: token.to_string(),
+            });
+        };
+        let Some(value) = self.spec.get(token) else {
+            return Ok(base);
+        };
+        if let Some(start) = self.stack.iter().position(|t| *t == token) {
+            let mut path: Vec<String> = self.stack[start..].iter().map(|t| t.to_string()).collect();
+            path.push(token.to_string());
+            return Err(TokenError::Cycle { path });
+        }
+
+        self.stack.push(token);
+        let color = match reference(value) {
+            Some(target) => {
+                if self.base.get(target).is_none() {
+                    return Err(TokenError::UnknownReference {
+                        token: token.to_string(),
+                        reference: target.to_string(),
+                    });
+                }
+                self.color(target)?
+            }
+            None => parse_hex(value).ok_or_else(|| TokenError::InvalidColor {
+                token: token.to_string(),
+                value: value.clone(),
+            })?,
+        };
+        self.stack.pop();
+        self.done.insert(token, color);
+        Ok(color)
+    }
+}
+
+/// The token name inside `{...}`, if `value` is a reference.
+fn reference(value: &str) -> Option<&str> {
+    value
+        .trim()
+        .strip_prefix('{')?
+        .strip_suffix('}')
+        .map(str::trim)
+}
+
+fn parse_hex(value: &str) -> Option<Color> {
+    let hex = value.trim().strip_prefix('#')?;
+    if !hex.is_ascii() || !matches!(hex.len(), 6 | 8) {
+        return None;
+    }
+    let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    let alpha = if hex.len() == 8 { byte(6)? } else { 255 };
+    Some(Color::rgba8(byte(0)?, byte(2)?, byte(4)?, alpha))
+}
@@ -0,0 +1,72 @@
+//! Modular type scale for generating font sizes
+
+use serde::{Deserialize, Serialize};
+
+use super::TypographyTokens;
+
+/// A modular type scale: every font size is `base * ratio^step`.
+///
+/// Body text sits at step 0, headings and titles one and two steps up, and
+/// labels, small, and tiny text at -0.5, -1, and -1.5 steps. The built-in
+/// palettes approximate `TypeScale::new(12.0, TypeScale::MINOR_THIRD)`.
+///
+/// ```rust
+/// use fleem::tokens::TypeScale;
+/// use fleem::Palette;
+///
+/// let roomy = Palette::dark().with_type_scale(TypeScale::new(14.0, TypeScale::MAJOR_THIRD));
+/// assert_eq!(roomy.typography.font_body, 14.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TypeScale {
+    /// Body font size in logical pixels.
+    pub base: f32,
+    /// Ratio between neighbouring steps.
+    pub ratio: f32,
+}
+
+impl TypeScale {
+    /// 15:16
+    pub const MINOR_SECOND: f32 = 1.067;
+    /// 8:9
+    pub const MAJOR_SECOND: f32 = 1.125;
+    /// 5:6
+    pub const MINOR_THIRD: f32 = 1.2;
+    /// 4:5
+    pub const MAJOR_THIRD: f32 = 1.25;
+    /// 3:4
+    pub const PERFECT_FOURTH: f32 = 1.333;
+    /// 1:1.618
+    pub const GOLDEN_RATIO: f32 = 1.618;
+
+    /// Create a scale from a body size and a step ratio.
+    pub const fn new(base: f32, ratio: f32) -> Self {
+        Self { base, ratio }
+    }
+
+    /// Font size `step` steps away from the base.
+    pub fn size(self, step: f32) -> f32 {
+        self.base * self.ratio.powf(step)
+    }
+
+    /// Replace the font sizes in `tokens` with sizes from this scale.
+    ///
+    /// Families, weights, line heights, and letter spacing are kept.
+    pub fn apply(self, tokens: TypographyTokens) -> TypographyTokens {
+        TypographyTokens {
+            font_title: self.size(2.0),
+            font_heading: self.size(1.0),
+            font_body: self.size(0.0),
+            font_label: self.size(-0.5),
+            font_small: self.size(-1.0),
+            font_tiny: self.size(-1.5),
+            ..tokens
+        }
+    }
+}
+
+impl Default for TypeScale {
+    fn default() -> Self {
+        Self::new(12.0, Self::MINOR_THIRD)
+    }
+}
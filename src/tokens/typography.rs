@@ -1,8 +1,17 @@
 //! Typography tokens for consistent text sizing
 
-/// Font size tokens for a theme palette.
+use floem::text::Weight;
+
+use crate::blend::lerp_f32;
+
+/// Font family, size, weight, and spacing tokens for a theme palette.
 ///
-/// Values are in logical pixels (f32), matching floem's `font_size()`.
+/// Sizes and letter spacing are in logical pixels (f32), matching floem's
+/// `font_size()`. Line heights are multiples of the font size.
+///
+/// Letter spacing is carried for custom renderers and future floem versions;
+/// floem 0.2 has no letter-spacing style property, so the text recipes in
+/// [`crate::styles`] don't apply it.
 #[derive(Debug, Clone, Copy)]
 pub struct TypographyTokens {
     /// Title text (18.0)
@@ -17,6 +26,131 @@ pub struct TypographyTokens {
     pub font_small: f32,
     /// Tiny text (9.0)
     pub font_tiny: f32,
+
+    // Families
+    /// Font family for UI and body text ("sans-serif")
+    pub font_ui: &'static str,
+    /// Font family for titles and headings ("sans-serif")
+    pub font_heading_family: &'static str,
     /// Monospace font family name
     pub font_mono: &'static str,
+
+    // Per-script fallbacks, tried in order after the primary family
+    /// Fallback families for Chinese, Japanese, and Korean text
+    pub fallback_cjk: &'static [&'static str],
+    /// Fallback families for Arabic script
+    pub fallback_arabic: &'static [&'static str],
+    /// Fallback families for emoji
+    pub fallback_emoji: &'static [&'static str],
+
+    // Weights
+    /// Title weight (bold)
+    pub weight_title: Weight,
+    /// Heading weight (semibold)
+    pub weight_heading: Weight,
+    /// Body weight (normal)
+    pub weight_body: Weight,
+    /// Caption and label weight (normal)
+    pub weight_caption: Weight,
+
+    // Line heights
+    /// Line height for titles and headings (1.2)
+    pub line_height_tight: f32,
+    /// Line height for body text and captions (1.4)
+    pub line_height_normal: f32,
+
+    // Letter spacing
+    /// Letter spacing for titles (-0.2)
+    pub letter_spacing_title: f32,
+    /// Letter spacing for body text (0.0)
+    pub letter_spacing_body: f32,
+    /// Letter spacing for captions (0.2)
+    pub letter_spacing_caption: f32,
+}
+
+impl TypographyTokens {
+    /// Default CJK fallbacks for Linux, macOS, and Windows.
+    pub const CJK_FALLBACK: &'static [&'static str] = &[
+        "Noto Sans CJK SC",
+        "PingFang SC",
+        "Hiragino Sans",
+        "Microsoft YaHei",
+    ];
+    /// Default Arabic fallbacks for Linux, macOS, and Windows.
+    pub const ARABIC_FALLBACK: &'static [&'static str] =
+        &["Noto Sans Arabic", "Geeza Pro", "Segoe UI"];
+    /// Default emoji fallbacks for Linux, macOS, and Windows.
+    pub const EMOJI_FALLBACK: &'static [&'static str] =
+        &["Noto Color Emoji", "Apple Color Emoji", "Segoe UI Emoji"];
+
+    /// Comma-separated family list for floem's `font_family()`: `primary`
+    /// followed by the CJK, Arabic, and emoji fallbacks.
+    ///
+    /// ```rust
+    /// let t = fleem::Palette::dark().typography;
+    /// assert!(t.family_stack(t.font_ui).starts_with("sans-serif, Noto Sans CJK SC"));
+    /// ```
+    pub fn family_stack(&self, primary: &str) -> String {
+        let fallbacks = self
+            .fallback_cjk
+            .iter()
+            .chain(self.fallback_arabic)
+            .chain(self.fallback_emoji);
+        let mut stack = primary.to_string();
+        for family in fallbacks {
+            stack.push_str(", ");
+            stack.push_str(family);
+        }
+        stack
+    }
+
+    /// Multiply every font size and letter spacing by `factor`.
+    ///
+    /// Line heights are relative to the font size and stay unchanged.
+    pub fn scaled(self, factor: f32) -> Self {
+        Self {
+            font_title: self.font_title * factor,
+            font_heading: self.font_heading * factor,
+            font_body: self.font_body * factor,
+            font_label: self.font_label * factor,
+            font_small: self.font_small * factor,
+            font_tiny: self.font_tiny * factor,
+            letter_spacing_title: self.letter_spacing_title * factor,
+            letter_spacing_body: self.letter_spacing_body * factor,
+            letter_spacing_caption: self.letter_spacing_caption * factor,
+            ..self
+        }
+    }
+
+    /// Interpolate every size, line height, and letter spacing between `a`
+    /// and `b`.
+    ///
+    /// `t` is clamped to `0.0..=1.0`. Non-numeric tokens like `font_mono`,
+    /// and the weights, come from the nearer endpoint.
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        let near = if t < 0.5 { a } else { b };
+        Self {
+            font_title: lerp_f32(a.font_title, b.font_title, t),
+            font_heading: lerp_f32(a.font_heading, b.font_heading, t),
+            font_body: lerp_f32(a.font_body, b.font_body, t),
+            font_label: lerp_f32(a.font_label, b.font_label, t),
+            font_small: lerp_f32(a.font_small, b.font_small, t),
+            font_tiny: lerp_f32(a.font_tiny, b.font_tiny, t),
+            font_ui: near.font_ui,
+            font_heading_family: near.font_heading_family,
+            font_mono: near.font_mono,
+            fallback_cjk: near.fallback_cjk,
+            fallback_arabic: near.fallback_arabic,
+            fallback_emoji: near.fallback_emoji,
+            weight_title: near.weight_title,
+            weight_heading: near.weight_heading,
+            weight_body: near.weight_body,
+            weight_caption: near.weight_caption,
+            line_height_tight: lerp_f32(a.line_height_tight, b.line_height_tight, t),
+            line_height_normal: lerp_f32(a.line_height_normal, b.line_height_normal, t),
+            letter_spacing_title: lerp_f32(a.letter_spacing_title, b.letter_spacing_title, t),
+            letter_spacing_body: lerp_f32(a.letter_spacing_body, b.letter_spacing_body, t),
+            letter_spacing_caption: lerp_f32(a.letter_spacing_caption, b.letter_spacing_caption, t),
+        }
+    }
 }
@@ -0,0 +1,176 @@
+//! Animated cross-fade between palettes after theme or appearance changes
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use floem::action::exec_after;
+use floem_reactive::{RwSignal, Scope, SignalGet, SignalUpdate};
+
+use crate::motion::reduced_motion;
+use crate::palette::Palette;
+use crate::theme::ResolvedAppearance;
+
+/// Interval between animation frames.
+const FRAME: Duration = Duration::from_millis(16);
+
+/// Progress of one palette source (the global theme or a `ThemeScope`).
+struct Track {
+    /// Theme id and resolved appearance the track is heading to.
+    key: (u64, ResolvedAppearance),
+    /// Palette shown when the current transition started.
+    from: Palette,
+    /// Palette returned by the most recent `apply` call.
+    current: Palette,
+    /// Start of the running transition, if any.
+    start: Option<Instant>,
+}
+
+thread_local! {
+    static DURATION: Cell<Option<Duration>> = const { Cell::new(None) };
+    static TRACKS: RefCell<HashMap<u64, Track>> = RefCell::new(HashMap::new());
+    static TICKING: Cell<bool> = const { Cell::new(false) };
+    /// Bumped every frame while a transition runs; read by `apply` so style
+    /// closures re-run for each frame. Owned by its own root scope so it
+    /// outlives whichever scope happens to touch it first.
+    static FRAME_TICK: RwSignal<u64> = Scope::new().create_rw_signal(0);
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Added to `Instant::now()`, so tests can move a transition forward.
+    static TIME_SHIFT: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+
+#[cfg(not(test))]
+fn now() -> Instant {
+    Instant::now()
+}
+
+#[cfg(test)]
+fn now() -> Instant {
+    Instant::now() + TIME_SHIFT.with(Cell::get)
+}
+
+/// Move the transition clock forward by `by`.
+#[cfg(test)]
+pub(crate) fn advance_clock(by: Duration) {
+    TIME_SHIFT.with(|t| t.set(t.get() + by));
+}
+
+/// Cross-fade palettes over `duration` after theme or appearance changes.
+///
+/// While a transition runs, `palette()` returns a palette interpolated from
+/// the one shown before the change, with colors blended in OKLab and
+/// dimensions lerped. `None` (the default) switches instantly. Transitions
+/// are skipped while [`reduced_motion`] is set.
+pub fn set_palette_transition(duration: Option<Duration>) {
+    DURATION.with(|d| d.set(duration.filter(|d| !d.is_zero())));
+}
+
+/// The configured palette transition duration.
+pub fn palette_transition() -> Option<Duration> {
+    DURATION.with(Cell::get)
+}
+
+/// Whether any palette transition is running.
+///
+/// A track whose source stopped being read is treated as finished once its
+/// duration has elapsed, so the frame ticker always stops.
+pub(crate) fn in_progress() -> bool {
+    let Some(duration) = palette_transition() else {
+        return false;
+    };
+    TRACKS.with(|tracks| {
+        tracks
+            .borrow()
+            .values()
+            .any(|t| t.start.is_some_and(|start| now() - start < duration))
+    })
+}
+
+/// Run `target` through the transition for `source`.
+///
+/// `source` is 0 for the global theme or a `ThemeScope` id. A change in
+/// `key` starts a transition from the palette last returned for `source`.
+pub(crate) fn apply(source: u64, key: (u64, ResolvedAppearance), target: Palette) -> Palette {
+    let duration = match palette_transition() {
+        Some(duration) if !reduced_motion() => duration,
+        _ => {
+            TRACKS.with(|tracks| tracks.borrow_mut().remove(&source));
+            return target;
+        }
+    };
+
+    let (palette, animating) = TRACKS.with(|tracks| {
+        let mut tracks = tracks.borrow_mut();
+        let track = tracks.entry(source).or_insert(Track {
+            key,
+            from: target,
+            current: target,
+            start: None,
+        });
+
+        if track.key != key {
+            track.key = key;
+            track.from = track.current;
+            track.start = Some(now());
+        }
+
+        let palette = match track.start {
+            Some(start) => {
+                let t = (now() - start).as_secs_f32() / duration.as_secs_f32();
+                if t >= 1.0 {
+                    track.start = None;
+                    target
+                } else {
+                    Palette::lerp(&track.from, &target, ease_in_out(t))
+                }
+            }
+            None => target,
+        };
+        track.current = palette;
+        (palette, track.start.is_some())
+    });
+
+    if animating {
+        FRAME_TICK.with(|tick| tick.get());
+        start_ticker();
+    }
+    palette
+}
+
+fn ease_in_out(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// Bump `FRAME_TICK` every frame until no transition is running.
+fn start_ticker() {
+    if TICKING.with(|t| t.replace(true)) {
+        return;
+    }
+    fn tick() {
+        exec_after(FRAME, |_| {
+            if frame() {
+                tick();
+            }
+        });
+    }
+    tick();
+}
+
+/// Run one ticker frame. Returns whether the ticker keeps going.
+pub(crate) fn frame() -> bool {
+    FRAME_TICK.with(|tick| tick.update(|n| *n += 1));
+    let running = in_progress();
+    if !running {
+        TICKING.with(|t| t.set(false));
+    }
+    running
+}
+
+/// Whether the frame ticker is scheduled.
+#[cfg(test)]
+pub(crate) fn ticking() -> bool {
+    TICKING.with(Cell::get)
+}
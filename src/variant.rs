@@ -1,7 +1,12 @@
 //! Variant, Size, and Fill enums with palette-based color resolution
 
+use std::sync::Mutex;
+
+use floem::kurbo::Stroke;
 use floem::prelude::Color;
+use serde::{Deserialize, Serialize};
 
+use crate::blend::lerp_color;
 use crate::palette::Palette;
 
 /// Semantic variant for UI elements.
@@ -17,11 +22,15 @@ pub enum Variant {
     Neutral,
     Ghost,
     Link,
+    /// A variant registered on the palette; see [`CustomVariant`].
+    Custom(CustomVariantId),
 }
 
 /// Size scale for UI elements.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Size {
+    /// Hero calls to action.
+    XLarge,
     Large,
     #[default]
     Normal,
@@ -35,6 +44,54 @@ pub enum Fill {
     #[default]
     Filled,
     Outlined,
+    /// Tinted background with variant-colored text, for secondary emphasis.
+    Soft,
+    /// Dashed outline for "add new" affordances, with a tinted hover.
+    Dashed,
+}
+
+impl Fill {
+    /// Border stroke of `width` for this fill: dashed for `Fill::Dashed`,
+    /// solid otherwise.
+    pub fn stroke(self, width: f32) -> Stroke {
+        let width = f64::from(width);
+        let stroke = Stroke::new(width);
+        match self {
+            Fill::Dashed => stroke.with_dashes(0.0, [width * 4.0, width * 3.0]),
+            _ => stroke,
+        }
+    }
+}
+
+/// Corner style for controls.
+///
+/// Set on the palette for a theme-wide look, or per call with the `*_shaped`
+/// recipes such as [`crate::styles::button_style_shaped`]. Applies to the
+/// radii of buttons, badges, inputs, checkboxes, chips, and toggle tracks;
+/// cards and modals keep their own radius tokens.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Shape {
+    /// Sharp corners.
+    Square,
+    /// The palette's radius tokens.
+    #[default]
+    Rounded,
+    /// Fully rounded ends.
+    Pill,
+}
+
+impl Shape {
+    /// Radius large enough to fully round any control.
+    pub const PILL_RADIUS: f32 = 9999.0;
+
+    /// Corner radius for a control whose rounded radius is `rounded`.
+    pub fn radius(self, rounded: f32) -> f32 {
+        match self {
+            Shape::Square => 0.0,
+            Shape::Rounded => rounded,
+            Shape::Pill => Self::PILL_RADIUS,
+        }
+    }
 }
 
 /// Resolved colors for a variant + fill combination.
@@ -42,137 +99,364 @@ pub enum Fill {
 pub struct VariantColors {
     pub bg: Color,
     pub bg_hover: Color,
+    /// Background while pressed.
+    pub bg_active: Color,
     pub fg: Color,
+    pub fg_hover: Color,
     pub border: Color,
+    pub border_hover: Color,
+}
+
+/// Id of a theme-registered variant, used with [`Variant::Custom`].
+///
+/// Ids are a hash of the variant's name, so the same name always gives the
+/// same id, whether it is a constant or read from a theme file at runtime:
+///
+/// ```rust
+/// use fleem::CustomVariantId;
+///
+/// pub const PREMIUM: CustomVariantId = CustomVariantId::new("premium");
+/// let loaded = String::from("premium");
+/// assert_eq!(CustomVariantId::new(&loaded), PREMIUM);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomVariantId(u64);
+
+impl CustomVariantId {
+    /// Create an id from a name unique within the theme.
+    pub const fn new(name: &str) -> Self {
+        // 64-bit FNV-1a, which can run in a const fn.
+        let bytes = name.as_bytes();
+        let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+        let mut i = 0;
+        while i < bytes.len() {
+            hash ^= bytes[i] as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+            i += 1;
+        }
+        Self(hash)
+    }
+}
+
+/// The four colors a status-like variant is built from, mirroring the
+/// `success`, `success_hover`, `success_bg`, and `success_fg` tokens.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VariantTokens {
+    /// Solid color: filled background, outline, and outlined text.
+    pub color: Color,
+    /// Filled background on hover.
+    pub hover: Color,
+    /// Tinted background for soft fills and badges.
+    pub bg: Color,
+    /// Text on `color`. Text on `bg` uses `color` itself.
+    pub fg: Color,
+}
+
+impl VariantTokens {
+    /// Resolve to concrete colors for `fill`.
+    pub fn resolve(self, fill: Fill) -> VariantColors {
+        match fill {
+            Fill::Filled => VariantColors {
+                bg: self.color,
+                bg_hover: self.hover,
+                bg_active: pressed(self.hover),
+                fg: self.fg,
+                fg_hover: self.fg,
+                border: self.color,
+                border_hover: self.hover,
+            },
+            Fill::Outlined => VariantColors {
+                bg: Color::TRANSPARENT,
+                bg_hover: self.bg,
+                bg_active: pressed(self.bg),
+                fg: self.color,
+                fg_hover: self.color,
+                border: self.color,
+                border_hover: self.color,
+            },
+            Fill::Soft => VariantColors {
+                bg: self.bg,
+                bg_hover: lerp_color(self.bg, self.color, 0.15),
+                bg_active: lerp_color(self.bg, self.color, 0.3),
+                fg: self.color,
+                fg_hover: self.color,
+                border: Color::TRANSPARENT,
+                border_hover: Color::TRANSPARENT,
+            },
+            Fill::Dashed => dashed(self.resolve(Fill::Outlined), self.resolve(Fill::Soft)),
+        }
+    }
+}
+
+/// A variant registered on a theme's palette.
+///
+/// ```rust
+/// use floem::prelude::Color;
+/// use fleem::{CustomVariant, CustomVariantId, Palette, Variant, VariantTokens};
+///
+/// const PREMIUM: CustomVariantId = CustomVariantId::new("premium");
+/// let premium = CustomVariant::new(
+///     "premium",
+///     VariantTokens {
+///         color: Color::rgb8(126, 87, 194),
+///         hover: Color::rgb8(149, 117, 205),
+///         bg: Color::rgb8(45, 35, 65),
+///         fg: Color::WHITE,
+///     },
+/// );
+///
+/// let palette = Palette::dark().with_custom_variants([premium]);
+/// let colors = Variant::Custom(PREMIUM).resolve(fleem::Fill::Filled, &palette);
+/// assert_eq!(colors.bg, Color::rgb8(126, 87, 194));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CustomVariant {
+    pub id: CustomVariantId,
+    pub tokens: VariantTokens,
+}
+
+impl CustomVariant {
+    /// A variant named `name`, usable as `Variant::Custom(CustomVariantId::new(name))`.
+    pub fn new(name: &str, tokens: VariantTokens) -> Self {
+        Self {
+            id: CustomVariantId::new(name),
+            tokens,
+        }
+    }
+}
+
+/// Custom variant lists registered at runtime, each stored once.
+static REGISTERED: Mutex<Vec<&'static [CustomVariant]>> = Mutex::new(Vec::new());
+
+/// A `'static` copy of `variants`, so palettes can hold it and stay `Copy`.
+///
+/// Equal lists share one allocation, which lives for the rest of the
+/// program; re-registering a theme's variants doesn't grow memory.
+pub(crate) fn register_variants(variants: Vec<CustomVariant>) -> &'static [CustomVariant] {
+    if variants.is_empty() {
+        return &[];
+    }
+    let mut registered = REGISTERED.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(&known) = registered
+        .iter()
+        .find(|&&known| known == variants.as_slice())
+    {
+        return known;
+    }
+    let stored: &'static [CustomVariant] = variants.leak();
+    registered.push(stored);
+    stored
 }
 
 impl Variant {
+    /// Single accent color for this variant.
+    ///
+    /// Used by checked/on states. Variants without a semantic color fall back
+    /// to the palette accent.
+    pub fn accent(self, palette: &Palette) -> Color {
+        let c = &palette.colors;
+        match self {
+            Variant::Primary => c.accent,
+            Variant::Success => c.success,
+            Variant::Warning => c.warning,
+            Variant::Error => c.error,
+            Variant::Info => c.info,
+            Variant::Custom(id) => palette
+                .custom_variant(id)
+                .map_or(c.accent, |v| v.tokens.color),
+            _ => c.accent,
+        }
+    }
+
     /// Resolve this variant to concrete colors from the palette.
+    ///
+    /// A `Custom` variant the palette doesn't register resolves as `Primary`.
     pub fn resolve(self, fill: Fill, palette: &Palette) -> VariantColors {
         let c = &palette.colors;
 
-        match fill {
-            Fill::Filled => match self {
-                Variant::Primary => VariantColors {
+        match self {
+            Variant::Primary => match fill {
+                Fill::Filled => VariantColors {
                     bg: c.accent,
                     bg_hover: c.accent_hover,
+                    bg_active: pressed(c.accent_hover),
                     fg: c.accent_fg,
+                    fg_hover: c.accent_fg,
                     border: c.accent,
+                    border_hover: c.accent_hover,
                 },
-                Variant::Secondary => VariantColors {
-                    bg: c.bg_elevated,
-                    bg_hover: c.bg_hover,
-                    fg: c.text_primary,
-                    border: c.border,
-                },
-                Variant::Success => VariantColors {
-                    bg: c.success,
-                    bg_hover: c.success,
-                    fg: c.success_fg,
-                    border: c.success,
-                },
-                Variant::Warning => VariantColors {
-                    bg: c.warning,
-                    bg_hover: c.warning,
-                    fg: c.warning_fg,
-                    border: c.warning,
-                },
-                Variant::Error => VariantColors {
-                    bg: c.error,
-                    bg_hover: c.error,
-                    fg: c.error_fg,
-                    border: c.error,
-                },
-                Variant::Info => VariantColors {
-                    bg: c.info,
-                    bg_hover: c.info,
-                    fg: c.info_fg,
-                    border: c.info,
-                },
-                Variant::Neutral => VariantColors {
-                    bg: c.neutral,
-                    bg_hover: c.neutral_hover,
-                    fg: c.neutral_fg,
-                    border: c.neutral,
-                },
-                Variant::Ghost => VariantColors {
+                Fill::Outlined => VariantColors {
                     bg: Color::TRANSPARENT,
-                    bg_hover: c.bg_hover,
-                    fg: c.text_primary,
-                    border: Color::TRANSPARENT,
+                    bg_hover: c.accent,
+                    bg_active: pressed(c.accent),
+                    fg: c.accent,
+                    fg_hover: c.accent_fg,
+                    border: c.accent,
+                    border_hover: c.accent,
                 },
-                Variant::Link => VariantColors {
-                    bg: Color::TRANSPARENT,
-                    bg_hover: Color::TRANSPARENT,
-                    fg: c.text_link,
+                Fill::Soft => VariantColors {
+                    bg: lerp_color(c.bg_surface, c.accent, 0.15),
+                    bg_hover: lerp_color(c.bg_surface, c.accent, 0.25),
+                    bg_active: lerp_color(c.bg_surface, c.accent, 0.35),
+                    fg: c.accent,
+                    fg_hover: c.accent,
                     border: Color::TRANSPARENT,
+                    border_hover: Color::TRANSPARENT,
                 },
+                Fill::Dashed => dashed(
+                    self.resolve(Fill::Outlined, palette),
+                    self.resolve(Fill::Soft, palette),
+                ),
             },
-            Fill::Outlined => match self {
-                Variant::Primary => VariantColors {
-                    bg: Color::TRANSPARENT,
-                    bg_hover: c.accent,
-                    fg: c.accent,
-                    border: c.accent,
+            Variant::Secondary => match fill {
+                Fill::Filled => VariantColors {
+                    bg: c.bg_elevated,
+                    bg_hover: c.bg_hover,
+                    bg_active: c.bg_selected,
+                    fg: c.text_primary,
+                    fg_hover: c.text_primary,
+                    border: c.border,
+                    border_hover: c.border_strong,
                 },
-                Variant::Secondary => VariantColors {
+                Fill::Outlined => VariantColors {
                     bg: Color::TRANSPARENT,
                     bg_hover: c.bg_hover,
+                    bg_active: c.bg_selected,
                     fg: c.text_primary,
+                    fg_hover: c.text_primary,
                     border: c.border_strong,
+                    border_hover: c.text_muted,
                 },
-                Variant::Success => VariantColors {
-                    bg: Color::TRANSPARENT,
-                    bg_hover: c.success_bg,
-                    fg: c.success,
-                    border: c.success,
-                },
-                Variant::Warning => VariantColors {
-                    bg: Color::TRANSPARENT,
-                    bg_hover: c.warning_bg,
-                    fg: c.warning,
-                    border: c.warning,
-                },
-                Variant::Error => VariantColors {
-                    bg: Color::TRANSPARENT,
-                    bg_hover: c.error_bg,
-                    fg: c.error,
-                    border: c.error,
+                Fill::Soft => VariantColors {
+                    bg: c.bg_elevated,
+                    bg_hover: c.bg_hover,
+                    bg_active: c.bg_selected,
+                    fg: c.text_primary,
+                    fg_hover: c.text_primary,
+                    border: Color::TRANSPARENT,
+                    border_hover: Color::TRANSPARENT,
                 },
-                Variant::Info => VariantColors {
-                    bg: Color::TRANSPARENT,
-                    bg_hover: c.info_bg,
-                    fg: c.info,
-                    border: c.info,
+                Fill::Dashed => dashed(
+                    self.resolve(Fill::Outlined, palette),
+                    self.resolve(Fill::Soft, palette),
+                ),
+            },
+            Variant::Success => VariantTokens {
+                color: c.success,
+                hover: c.success_hover,
+                bg: c.success_bg,
+                fg: c.success_fg,
+            }
+            .resolve(fill),
+            Variant::Warning => VariantTokens {
+                color: c.warning,
+                hover: c.warning_hover,
+                bg: c.warning_bg,
+                fg: c.warning_fg,
+            }
+            .resolve(fill),
+            Variant::Error => VariantTokens {
+                color: c.error,
+                hover: c.error_hover,
+                bg: c.error_bg,
+                fg: c.error_fg,
+            }
+            .resolve(fill),
+            Variant::Info => VariantTokens {
+                color: c.info,
+                hover: c.info_hover,
+                bg: c.info_bg,
+                fg: c.info_fg,
+            }
+            .resolve(fill),
+            Variant::Neutral => match fill {
+                Fill::Filled => VariantColors {
+                    bg: c.neutral,
+                    bg_hover: c.neutral_hover,
+                    bg_active: pressed(c.neutral_hover),
+                    fg: c.neutral_fg,
+                    fg_hover: c.neutral_fg,
+                    border: c.neutral,
+                    border_hover: c.neutral_hover,
                 },
-                Variant::Neutral => VariantColors {
+                Fill::Outlined => VariantColors {
                     bg: Color::TRANSPARENT,
                     bg_hover: c.neutral,
+                    bg_active: pressed(c.neutral),
                     fg: c.neutral_fg,
+                    fg_hover: c.neutral_fg,
                     border: c.neutral,
+                    border_hover: c.neutral,
+                },
+                Fill::Soft => VariantColors {
+                    bg: lerp_color(c.bg_surface, c.neutral, 0.4),
+                    bg_hover: lerp_color(c.bg_surface, c.neutral, 0.6),
+                    bg_active: lerp_color(c.bg_surface, c.neutral, 0.8),
+                    fg: c.text_primary,
+                    fg_hover: c.text_primary,
+                    border: Color::TRANSPARENT,
+                    border_hover: Color::TRANSPARENT,
                 },
-                Variant::Ghost => VariantColors {
+                Fill::Dashed => dashed(
+                    self.resolve(Fill::Outlined, palette),
+                    self.resolve(Fill::Soft, palette),
+                ),
+            },
+            Variant::Ghost => match fill {
+                Fill::Filled | Fill::Outlined | Fill::Soft | Fill::Dashed => VariantColors {
                     bg: Color::TRANSPARENT,
                     bg_hover: c.bg_hover,
+                    bg_active: c.bg_selected,
                     fg: c.text_primary,
+                    fg_hover: c.text_primary,
                     border: Color::TRANSPARENT,
+                    border_hover: Color::TRANSPARENT,
                 },
-                Variant::Link => VariantColors {
+            },
+            Variant::Link => match fill {
+                Fill::Filled | Fill::Outlined | Fill::Soft | Fill::Dashed => VariantColors {
                     bg: Color::TRANSPARENT,
                     bg_hover: Color::TRANSPARENT,
+                    bg_active: Color::TRANSPARENT,
                     fg: c.text_link,
+                    fg_hover: c.accent_hover,
                     border: Color::TRANSPARENT,
+                    border_hover: Color::TRANSPARENT,
                 },
             },
+            Variant::Custom(id) => match palette.custom_variant(id) {
+                Some(custom) => custom.tokens.resolve(fill),
+                None => Variant::Primary.resolve(fill, palette),
+            },
         }
     }
 }
 
+/// Outline at rest like `outline`, tinted like `soft` on hover and press.
+fn dashed(outline: VariantColors, soft: VariantColors) -> VariantColors {
+    VariantColors {
+        bg_hover: soft.bg_hover,
+        bg_active: soft.bg_active,
+        fg_hover: outline.fg,
+        ..outline
+    }
+}
+
+/// Pressed shade of a state color: 15% darker, same alpha.
+fn pressed(color: Color) -> Color {
+    if color.a == 0 {
+        return color;
+    }
+    let dark = lerp_color(color, Color::rgba8(0, 0, 0, color.a), 0.15);
+    Color::rgba8(dark.r, dark.g, dark.b, color.a)
+}
+
 impl Size {
     /// Font size for this size level.
     pub fn font_size(self, palette: &Palette) -> f32 {
         let t = &palette.typography;
         match self {
+            Size::XLarge => t.font_title,
             Size::Large => t.font_heading,
             Size::Normal => t.font_body,
             Size::Small => t.font_label,
@@ -184,6 +468,7 @@ impl Size {
     pub fn padding(self, palette: &Palette) -> (f32, f32) {
         let s = &palette.spacing;
         match self {
+            Size::XLarge => (s.pad_xl, s.pad_xl * 1.5),
             Size::Large => (s.pad_lg, s.pad_xl),
             Size::Normal => (s.pad_md, s.pad_lg),
             Size::Small => (s.pad_sm, s.pad_md),
@@ -191,14 +476,34 @@ impl Size {
         }
     }
 
-    /// Border radius for this size level.
+    /// Border radius for this size level, following `palette.shape`.
     pub fn radius(self, palette: &Palette) -> f32 {
+        palette.shape.radius(self.rounded_radius(palette))
+    }
+
+    /// Border radius for this size level with `Shape::Rounded`.
+    pub(crate) fn rounded_radius(self, palette: &Palette) -> f32 {
         let s = &palette.spacing;
         match self {
-            Size::Large => s.radius_xl,
+            Size::XLarge | Size::Large => s.radius_xl,
             Size::Normal => s.radius_lg,
             Size::Small => s.radius_md,
             Size::Tiny => s.radius_sm,
         }
     }
+
+    /// Smallest width and height of a hit target at this size level, from
+    /// `palette.spacing.hit_target`, so it grows with UI scale. Compact
+    /// density never takes it below the size's absolute minimum (24, 32,
+    /// 40, or 48 px). Used for icon-only buttons.
+    pub fn min_target(self, palette: &Palette) -> f32 {
+        let base = palette.spacing.hit_target;
+        let (factor, floor) = match self {
+            Size::XLarge => (1.5, 48.0),
+            Size::Large => (1.25, 40.0),
+            Size::Normal => (1.0, 32.0),
+            Size::Small | Size::Tiny => (0.75, 24.0),
+        };
+        (base * factor).max(floor)
+    }
 }
@@ -0,0 +1,85 @@
+//! Per-window theme and appearance overrides
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use floem::views::Decorators;
+use floem::window::WindowId;
+use floem_reactive::{with_scope, Scope};
+
+use crate::palette::Palette;
+use crate::scope::{theme_scope, ThemeScope};
+use crate::theme::{Appearance, ThemeDef};
+
+thread_local! {
+    /// Each window's theme scope, with the reactive scope owning its signals.
+    static WINDOW_SCOPES: RefCell<HashMap<WindowId, (Scope, ThemeScope)>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Get the theme scope for a window, creating it on first use.
+///
+/// A window scope inherits from the global state set by `init()` until an
+/// override is set on it.
+pub fn window_scope(id: WindowId) -> ThemeScope {
+    WINDOW_SCOPES.with(|scopes| {
+        scopes
+            .borrow_mut()
+            .entry(id)
+            .or_insert_with(|| {
+                let cx = Scope::new();
+                (cx, with_scope(cx, ThemeScope::root))
+            })
+            .1
+    })
+}
+
+/// Build a window's root view under that window's theme scope.
+///
+/// The window's overrides are dropped when the root view is cleaned up, i.e.
+/// when the window closes.
+///
+/// ```rust,no_run
+/// use floem::views::label;
+/// use fleem::{Appearance, ThemedExt};
+///
+/// floem::new_window(
+///     |id| {
+///         fleem::set_window_appearance(id, Some(Appearance::Dark));
+///         fleem::with_window(id, || {
+///             label(|| "Preview").themed(|p, s| s.background(p.colors.bg_base))
+///         })
+///     },
+///     None,
+/// );
+/// ```
+pub fn with_window<V: Decorators + 'static>(id: WindowId, root: impl FnOnce() -> V) -> V::DV {
+    theme_scope(window_scope(id), root).on_cleanup(move || forget_window(id))
+}
+
+/// Override a window's appearance. `None` follows the global signal again.
+pub fn set_window_appearance(id: WindowId, appearance: Option<Appearance>) {
+    window_scope(id).set_appearance(appearance);
+}
+
+/// Override a window's theme. `None` follows the global theme again.
+pub fn set_window_theme(id: WindowId, theme: Option<Rc<dyn ThemeDef>>) {
+    window_scope(id).set_theme(theme);
+}
+
+/// The effective `Palette` for a window (reactive).
+pub fn window_palette(id: WindowId) -> Palette {
+    window_scope(id).palette()
+}
+
+/// Drop a window's overrides.
+///
+/// [`with_window`] does this when the window closes; call it yourself for
+/// windows whose root isn't built with `with_window`.
+pub fn forget_window(id: WindowId) {
+    let removed = WINDOW_SCOPES.with(|scopes| scopes.borrow_mut().remove(&id));
+    if let Some((cx, _)) = removed {
+        cx.dispose();
+    }
+}
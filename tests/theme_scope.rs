@@ -0,0 +1,59 @@
+//! Scoped palettes after global changes.
+//!
+//! `init()` installs process-wide state, so this lives in its own test binary
+//! instead of next to the unit tests in `src/lib.rs`.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use fleem::{
+    init, palette, theme_scope, Appearance, Density, Palette, ThemeDef, ThemeScope, ThemedExt,
+};
+use floem::prelude::Color;
+use floem_reactive::SignalUpdate;
+
+struct Sepia;
+
+impl ThemeDef for Sepia {
+    fn dark_palette(&self) -> Palette {
+        let mut p = Palette::dark();
+        p.colors.bg_base = Color::rgb8(40, 32, 24);
+        p
+    }
+    fn light_palette(&self) -> Palette {
+        let mut p = Palette::light();
+        p.colors.bg_base = Color::rgb8(244, 236, 216);
+        p
+    }
+    fn name(&self) -> &str {
+        "Sepia"
+    }
+}
+
+#[test]
+fn scoped_palette_survives_global_appearance_change() {
+    let appearance = init(Appearance::Dark);
+    let seen = Rc::new(Cell::new(None));
+
+    // The scope overrides theme and density but inherits the appearance, so
+    // a global appearance change re-runs the style closure after
+    // `theme_scope` has returned.
+    let scope = ThemeScope::new().theme(Sepia).density(Density::Compact);
+    let _view = theme_scope(scope, || {
+        let seen = seen.clone();
+        floem::views::empty().themed(move |p, s| {
+            seen.set(Some(*p));
+            s
+        })
+    });
+    let dark = seen.get().unwrap();
+    assert_eq!(dark.colors.bg_base, Sepia.dark_palette().colors.bg_base);
+
+    appearance.set(Appearance::Light);
+    let light = seen.get().unwrap();
+    assert_eq!(light.colors.bg_base, Sepia.light_palette().colors.bg_base);
+    assert!(light.spacing.pad_lg < palette().spacing.pad_lg);
+
+    // Outside the scope the global theme applies.
+    assert_eq!(palette().colors.bg_base, Palette::light().colors.bg_base);
+}